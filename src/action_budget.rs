@@ -0,0 +1,287 @@
+//! Tracking of the address-based action budget.
+//!
+//! Besides the per-IP weight budget, HyperLiquid caps the number of exchange actions an
+//! address may send: every address starts with a buffer of requests and earns one more per
+//! USDC of cumulative traded volume. A batch of `n` orders or cancels counts as `n`
+//! requests. An [`ActionBudget`] is seeded from the `userRateLimit` endpoint, counts actions
+//! locally as they are sent and warns or refuses before the cap is reached.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::client::HyperLiquidClient;
+use crate::errors::{HyperLiquidError, Result};
+use crate::info::user_rate_limit::UserRateLimitResponse;
+
+type WarningHandler = Arc<dyn Fn(&BudgetSnapshot) + Send + Sync>;
+
+/// Point-in-time view of an [`ActionBudget`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetSnapshot {
+    pub used: u64,
+    pub cap: u64,
+    /// Cumulative traded volume in USDC
+    pub cum_vlm: Decimal,
+}
+
+impl BudgetSnapshot {
+    /// Requests left before the cap
+    pub fn remaining(&self) -> u64 {
+        self.cap.saturating_sub(self.used)
+    }
+
+    /// Additional traded volume, in USDC, needed before `requests` more actions fit under
+    /// the cap
+    pub fn volume_needed(&self, requests: u64) -> Decimal {
+        Decimal::from((self.used + requests).saturating_sub(self.cap))
+    }
+}
+
+#[derive(Debug)]
+struct BudgetState {
+    used: u64,
+    /// Cap minus the whole USDC of volume it was earned with, i.e. the initial buffer
+    buffer: u64,
+    cum_vlm: Decimal,
+}
+
+impl BudgetState {
+    fn snapshot(&self) -> BudgetSnapshot {
+        BudgetSnapshot {
+            used: self.used,
+            cap: self.buffer + volume_requests(self.cum_vlm),
+            cum_vlm: self.cum_vlm,
+        }
+    }
+}
+
+/// Requests earned by a cumulative traded volume
+fn volume_requests(cum_vlm: Decimal) -> u64 {
+    cum_vlm.floor().to_u64().unwrap_or(0)
+}
+
+/// Local count of an address's exchange actions against its cap
+///
+/// Actions are counted when they are sent, whether or not the exchange accepts them, so the
+/// local count only errs on the side of caution. Actions that fail before reaching the
+/// exchange, e.g. because they cannot be signed or the connection cannot be opened, are not
+/// counted. Call [`HyperLiquidClient::sync_action_budget`]
+/// periodically to pick up volume traded since and correct any drift.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use hyperliquid::action_budget::ActionBudget;
+/// use hyperliquid::client::HyperLiquidClient;
+/// use hyperliquid::exchange::signing::Wallet;
+/// use std::sync::Arc;
+///
+/// # async fn example(wallet: Wallet) -> hyperliquid::errors::Result<()> {
+/// let client = HyperLiquidClient::new().with_wallet(wallet);
+/// let limits = client.get_user_rate_limit(client.wallet.as_ref().unwrap().address()).await?;
+///
+/// let budget = ActionBudget::from_rate_limit(&limits)
+///     .with_warn_threshold(1_000)
+///     .with_warning_handler(|snapshot| eprintln!("{} actions left", snapshot.remaining()));
+/// let client = client.with_action_budget(Arc::new(budget));
+/// # Ok(())
+/// # }
+/// ```
+pub struct ActionBudget {
+    state: Mutex<BudgetState>,
+    warn_threshold: u64,
+    reserve: u64,
+    on_warning: Option<WarningHandler>,
+}
+
+impl fmt::Debug for ActionBudget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActionBudget")
+            .field("snapshot", &self.snapshot())
+            .field("warn_threshold", &self.warn_threshold)
+            .field("reserve", &self.reserve)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ActionBudget {
+    /// Budget with `used` of `cap` requests spent at a cumulative volume of `cum_vlm`
+    pub fn new(used: u64, cap: u64, cum_vlm: Decimal) -> Self {
+        Self {
+            state: Mutex::new(BudgetState {
+                used,
+                buffer: cap.saturating_sub(volume_requests(cum_vlm)),
+                cum_vlm,
+            }),
+            warn_threshold: 0,
+            reserve: 0,
+            on_warning: None,
+        }
+    }
+
+    /// Budget seeded from a `userRateLimit` response
+    pub fn from_rate_limit(limits: &UserRateLimitResponse) -> Self {
+        Self::new(limits.n_requests_used, limits.n_requests_cap, limits.cum_vlm)
+    }
+
+    /// Call the warning handler whenever an action leaves fewer than `remaining` requests
+    pub fn with_warn_threshold(mut self, remaining: u64) -> Self {
+        self.warn_threshold = remaining;
+        self
+    }
+
+    /// Refuse actions that would leave fewer than `remaining` requests, keeping them for
+    /// e.g. cancels
+    pub fn with_reserve(mut self, remaining: u64) -> Self {
+        self.reserve = remaining;
+        self
+    }
+
+    pub fn with_warning_handler(mut self, handler: impl Fn(&BudgetSnapshot) + Send + Sync + 'static) -> Self {
+        self.on_warning = Some(Arc::new(handler));
+        self
+    }
+
+    pub fn snapshot(&self) -> BudgetSnapshot {
+        self.lock().snapshot()
+    }
+
+    /// Count `requests` actions against the budget
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HyperLiquidError::ActionBudgetExhausted`], without counting anything, if
+    /// the actions would leave fewer requests than the configured reserve.
+    pub fn spend(&self, requests: u64) -> Result<BudgetSnapshot> {
+        let snapshot = {
+            let mut state = self.lock();
+            let snapshot = state.snapshot();
+            if snapshot.remaining() < requests + self.reserve {
+                return Err(HyperLiquidError::ActionBudgetExhausted {
+                    used: snapshot.used,
+                    cap: snapshot.cap,
+                    volume_needed: snapshot.volume_needed(requests + self.reserve),
+                });
+            }
+            state.used += requests;
+            state.snapshot()
+        };
+
+        if snapshot.remaining() < self.warn_threshold {
+            if let Some(handler) = &self.on_warning {
+                handler(&snapshot);
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Stop counting `requests` actions that were never sent
+    pub fn refund(&self, requests: u64) {
+        let mut state = self.lock();
+        state.used = state.used.saturating_sub(requests);
+    }
+
+    /// Add traded volume, e.g. from fills, raising the cap accordingly
+    pub fn record_volume(&self, notional: Decimal) {
+        self.lock().cum_vlm += notional.abs();
+    }
+
+    /// Replace the local count with the exchange's view
+    pub fn sync(&self, limits: &UserRateLimitResponse) {
+        let mut state = self.lock();
+        state.used = limits.n_requests_used;
+        state.buffer = limits.n_requests_cap.saturating_sub(volume_requests(limits.cum_vlm));
+        state.cum_vlm = limits.cum_vlm;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BudgetState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl HyperLiquidClient {
+    /// Re-seed the client's action budget from `userRateLimit`
+    ///
+    /// Queries the vault address if one is set, otherwise the wallet's address. Does
+    /// nothing without an action budget.
+    pub async fn sync_action_budget(&self) -> Result<()> {
+        let Some(budget) = &self.action_budget else {
+            return Ok(());
+        };
+        let user = match (&self.vault_address, &self.wallet) {
+            (Some(vault_address), _) => *vault_address,
+            (None, Some(wallet)) => wallet.address(),
+            (None, None) => {
+                return Err(HyperLiquidError::SigningError(
+                    "No wallet configured: use HyperLiquidClient::with_wallet to track an action budget".to_string(),
+                ))
+            }
+        };
+
+        let limits = self.get_user_rate_limit(user).await?;
+        budget.sync(&limits);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    #[test]
+    fn test_spend_and_reserve() {
+        let budget = ActionBudget::new(9_990, 10_000, Decimal::ZERO).with_reserve(2);
+
+        assert_eq!(budget.spend(8).unwrap().remaining(), 2);
+        let error = budget.spend(1).unwrap_err();
+        assert!(matches!(
+            error,
+            HyperLiquidError::ActionBudgetExhausted { used: 9_998, cap: 10_000, volume_needed } if volume_needed == Decimal::from(1)
+        ));
+        assert_eq!(budget.snapshot().used, 9_998, "Refused actions are not counted");
+    }
+
+    #[test]
+    fn test_volume_raises_cap() {
+        let budget = ActionBudget::new(10_500, 10_500, Decimal::new(5005, 1));
+        assert_eq!(budget.snapshot().volume_needed(10), Decimal::from(10));
+        assert!(budget.spend(1).is_err());
+
+        budget.record_volume(Decimal::new(-25, 1));
+        assert_eq!(budget.snapshot().cap, 10_503);
+        assert_eq!(budget.spend(3).unwrap().remaining(), 0);
+    }
+
+    #[test]
+    fn test_warning_handler() {
+        let warnings = Arc::new(AtomicU64::new(0));
+        let counter = warnings.clone();
+        let budget = ActionBudget::new(0, 10, Decimal::ZERO)
+            .with_warn_threshold(5)
+            .with_warning_handler(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+
+        budget.spend(5).unwrap();
+        assert_eq!(warnings.load(Ordering::SeqCst), 0);
+        budget.spend(1).unwrap();
+        assert_eq!(warnings.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_sync_replaces_local_count() {
+        let budget = ActionBudget::new(0, 10_000, Decimal::ZERO);
+        budget.spend(50).unwrap();
+
+        budget.sync(&UserRateLimitResponse {
+            cum_vlm: Decimal::new(12345, 1),
+            n_requests_used: 40,
+            n_requests_cap: 11_234,
+        });
+        let snapshot = budget.snapshot();
+        assert_eq!((snapshot.used, snapshot.cap), (40, 11_234));
+    }
+}
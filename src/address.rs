@@ -0,0 +1,241 @@
+//! Ethereum addresses with EIP-55 checksum support.
+//!
+//! [`Address`] holds the 20 raw bytes of an address, so two addresses compare equal
+//! whatever case they were written in. It parses `0x`- or `0X`-prefixed hex, checks the
+//! EIP-55 checksum of mixed-case input, and displays and serializes in the lowercase form
+//! the API uses. Every endpoint taking an address accepts anything implementing
+//! [`IntoAddress`]: an `Address`, a `&str` or a `String`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::{HyperLiquidError, Result};
+use crate::exchange::signing::keccak256;
+
+/// A 20-byte Ethereum address
+///
+/// # Examples
+///
+/// ```rust
+/// use hyperliquid::address::Address;
+///
+/// let address: Address = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".parse()?;
+/// assert_eq!(address.to_string(), "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+/// assert_eq!(address.to_checksum(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// assert_eq!(address, Address::try_from("0X5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED")?);
+///
+/// // Mixed case must carry a valid checksum
+/// assert!("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".parse::<Address>().is_err());
+/// # Ok::<(), hyperliquid::errors::HyperLiquidError>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Address([u8; 20]);
+
+impl Address {
+    /// The zero address
+    pub const ZERO: Address = Address([0; 20]);
+
+    pub const fn from_bytes(bytes: [u8; 20]) -> Self {
+        Address(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+
+    /// EIP-55 mixed-case checksum form
+    pub fn to_checksum(&self) -> String {
+        let lower = hex::encode(self.0);
+        let hash = keccak256(lower.as_bytes());
+        let checksummed: String = lower
+            .char_indices()
+            .map(|(index, c)| {
+                let nibble = (hash[index / 2] >> if index % 2 == 0 { 4 } else { 0 }) & 0x0f;
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+        format!("0x{}", checksummed)
+    }
+}
+
+impl FromStr for Address {
+    type Err = HyperLiquidError;
+
+    /// Parse `0x`/`0X` followed by 40 hex digits; mixed-case digits must match the EIP-55
+    /// checksum
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = |reason: String| HyperLiquidError::InvalidAddress(format!("Invalid Ethereum address '{}': {}", s, reason));
+
+        let Some(digits) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) else {
+            return Err(invalid("must start with '0x'".to_string()));
+        };
+        if s.len() != 42 {
+            return Err(invalid(format!("must be 42 characters long, got {} characters", s.len())));
+        }
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid("contains non-hexadecimal characters".to_string()));
+        }
+
+        let mut bytes = [0u8; 20];
+        hex::decode_to_slice(digits, &mut bytes).map_err(|e| invalid(e.to_string()))?;
+        let address = Address(bytes);
+
+        let has_lower = digits.chars().any(|c| c.is_ascii_lowercase());
+        let has_upper = digits.chars().any(|c| c.is_ascii_uppercase());
+        if has_lower && has_upper && address.to_checksum()[2..] != *digits {
+            return Err(invalid(format!("invalid EIP-55 checksum, expected {}", address.to_checksum())));
+        }
+        Ok(address)
+    }
+}
+
+impl TryFrom<&str> for Address {
+    type Error = HyperLiquidError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl TryFrom<String> for Address {
+    type Error = HyperLiquidError;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+impl From<[u8; 20]> for Address {
+    fn from(bytes: [u8; 20]) -> Self {
+        Address(bytes)
+    }
+}
+
+/// Lowercase `0x`-prefixed hex, the form the API uses
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Address({})", self)
+    }
+}
+
+/// Compares with a string in any case, e.g. `fill.user == "0xAbC…"`
+impl PartialEq<str> for Address {
+    fn eq(&self, other: &str) -> bool {
+        other
+            .get(2..)
+            .filter(|_| other.len() == 42 && other.as_bytes()[..2].eq_ignore_ascii_case(b"0x"))
+            .and_then(|digits| {
+                let mut bytes = [0u8; 20];
+                hex::decode_to_slice(digits, &mut bytes).ok().map(|_| bytes)
+            })
+            .is_some_and(|bytes| bytes == self.0)
+    }
+}
+
+impl PartialEq<&str> for Address {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Values accepted wherever an endpoint takes an address
+///
+/// Strings are parsed and validated when the request is made, so an invalid address fails
+/// with [`HyperLiquidError::InvalidAddress`] before anything is sent.
+pub trait IntoAddress {
+    fn into_address(self) -> Result<Address>;
+}
+
+impl IntoAddress for Address {
+    fn into_address(self) -> Result<Address> {
+        Ok(self)
+    }
+}
+
+impl IntoAddress for &Address {
+    fn into_address(self) -> Result<Address> {
+        Ok(*self)
+    }
+}
+
+impl IntoAddress for &str {
+    fn into_address(self) -> Result<Address> {
+        self.parse()
+    }
+}
+
+impl IntoAddress for String {
+    fn into_address(self) -> Result<Address> {
+        self.parse()
+    }
+}
+
+impl IntoAddress for &String {
+    fn into_address(self) -> Result<Address> {
+        self.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eip55_checksums() {
+        // Test vectors from EIP-55
+        for checksummed in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let address: Address = checksummed.parse().unwrap();
+            assert_eq!(address.to_checksum(), checksummed);
+            assert_eq!(address.to_string(), checksummed.to_lowercase());
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let message = |s: &str| s.parse::<Address>().unwrap_err().to_string();
+
+        assert!(message("1234567890123456789012345678901234567890").contains("must start with '0x'"));
+        assert!(message("0x123").contains("must be 42 characters long, got 5"));
+        assert!(message("0x123456789012345678901234567890123456789G").contains("non-hexadecimal"));
+        assert!(message("0xFB6916095ca1df60bB79Ce92cE3Ea74c37c5d359").contains("expected 0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"));
+    }
+
+    #[test]
+    fn test_serde_and_comparisons() {
+        let address: Address = serde_json::from_str(r#""0xFB6916095CA1DF60BB79CE92CE3EA74C37C5D359""#).unwrap();
+        assert_eq!(serde_json::to_string(&address).unwrap(), r#""0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359""#);
+        assert_eq!(address, "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359");
+        assert_ne!(address, "0x0000000000000000000000000000000000000000");
+        assert_ne!(address, "0é123456789012345678901234567890123456789");
+        assert!(serde_json::from_str::<Address>(r#""Leader""#).is_err());
+    }
+}
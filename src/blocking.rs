@@ -0,0 +1,176 @@
+//! Synchronous client for code that does not run an async runtime.
+//!
+//! [`HyperLiquidClient`] wraps the async [`crate::client::HyperLiquidClient`] and drives
+//! each call on its own single-threaded Tokio runtime, so requests, validation, retries,
+//! rate limiting and middleware behave exactly as they do asynchronously.
+//!
+//! Like `reqwest::blocking`, it must not be used from within an async runtime: calling it
+//! from async code panics. Use the async client there instead.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::address::IntoAddress;
+use crate::config::HyperLiquidConfig;
+use crate::errors::{HyperLiquidError, Result};
+use crate::info::all_mids::MidPrices;
+use crate::info::candle_snapshot::CandleData;
+use crate::info::clearinghouse_state::{ClearinghouseState, SpotState};
+use crate::info::delegations::DelegationsResponse;
+use crate::info::delegator_history::DelegatorHistoryResponse;
+use crate::info::delegator_rewards::DelegatorRewardsResponse;
+use crate::info::delegator_summary::DelegatorSummaryResponse;
+use crate::info::frontend_open_orders::FrontendOpenOrdersResponse;
+use crate::info::funding::{FundingHistory, PredictedFundingsResponse, UserFunding};
+use crate::info::historical_orders::HistoricalOrdersResponse;
+use crate::info::l2_book::L2BookResponse;
+use crate::info::ledger_updates::LedgerUpdate;
+use crate::info::max_builder_fee::MaxBuilderFeeResponse;
+use crate::info::meta::{Meta, MetaAndAssetCtxsResponse};
+use crate::info::open_orders::OpenOrdersResponse;
+use crate::info::order_status::OrderStatusResponse;
+use crate::info::portfolio::PortfolioResponse;
+use crate::info::referral::ReferralResponse;
+use crate::info::spot_meta::{SpotMeta, SpotMetaAndAssetCtxsResponse};
+use crate::info::sub_accounts::SubAccountsResponse;
+use crate::info::user_fees::UserFeesResponse;
+use crate::info::user_fills::UserFillsResponse;
+use crate::info::user_fills_by_time::UserFillsByTimeResponse;
+use crate::info::user_rate_limit::UserRateLimitResponse;
+use crate::info::user_role::UserRoleResponse;
+use crate::info::user_twap_slice_fills::UserTwapSliceFillsResponse;
+use crate::info::user_vault_equities::UserVaultEquitiesResponse;
+use crate::info::vault_details::VaultDetailsResponse;
+use crate::transport::Transport;
+
+/// Blocking client for the info endpoints
+///
+/// Cheap to clone; clones share the connection pool and runtime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use hyperliquid::blocking::HyperLiquidClient;
+///
+/// let client = HyperLiquidClient::new()?;
+/// let fees = client.get_user_fees("0x1234567890123456789012345678901234567890")?;
+/// println!("{:?}", fees);
+/// # Ok::<(), hyperliquid::errors::HyperLiquidError>(())
+/// ```
+#[derive(Clone)]
+pub struct HyperLiquidClient {
+    inner: crate::client::HyperLiquidClient,
+    runtime: Arc<Runtime>,
+}
+
+impl HyperLiquidClient {
+    /// Create a client with the default configuration
+    pub fn new() -> Result<Self> {
+        Self::with_config(HyperLiquidConfig::default())
+    }
+
+    /// Create a client with a custom configuration
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::client::HyperLiquidClient::with_config`], or
+    /// [`HyperLiquidError::ConfigError`] if the runtime cannot be started.
+    pub fn with_config(config: HyperLiquidConfig) -> Result<Self> {
+        Self::from_async(crate::client::HyperLiquidClient::with_config(config)?)
+    }
+
+    /// Create a client from [`HyperLiquidConfig::from_env`]
+    pub fn from_env() -> Result<Self> {
+        Self::with_config(HyperLiquidConfig::from_env()?)
+    }
+
+    /// Create a client from [`HyperLiquidConfig::from_file`]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_config(HyperLiquidConfig::from_file(path)?)
+    }
+
+    /// Drive an existing async client, keeping its transport, wallet and action budget
+    pub fn from_async(client: crate::client::HyperLiquidClient) -> Result<Self> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| HyperLiquidError::ConfigError(format!("Failed to start runtime: {}", e)))?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Send requests through a different transport, e.g. a
+    /// [`crate::transport::MemoryTransport`] in tests
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.inner = self.inner.with_transport(transport);
+        self
+    }
+
+    /// The async client every call is forwarded to
+    pub fn async_client(&self) -> &crate::client::HyperLiquidClient {
+        &self.inner
+    }
+}
+
+impl std::fmt::Debug for HyperLiquidClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HyperLiquidClient")
+            .field("base_url", &self.inner.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Blocking wrappers with the same name, arguments and result as the async methods
+macro_rules! blocking_methods {
+    ($(fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $output:ty;)*) => {
+        impl HyperLiquidClient {
+            $(
+                #[doc = concat!("Blocking [`crate::client::HyperLiquidClient::", stringify!($name), "`]")]
+                pub fn $name(&self $(, $arg: $ty)*) -> Result<$output> {
+                    self.runtime.block_on(self.inner.$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+blocking_methods! {
+    fn get_all_mids(&self) -> MidPrices;
+    fn get_candle_snapshot(&self, coin: &str, interval: &str, start_time: u64, end_time: u64) -> Vec<CandleData>;
+    fn get_clearinghouse_state(&self, user: impl IntoAddress, dex: Option<&str>) -> ClearinghouseState;
+    fn get_spot_clearinghouse_state(&self, user: impl IntoAddress) -> SpotState;
+    fn get_delegations(&self, user: impl IntoAddress) -> DelegationsResponse;
+    fn get_delegator_history(&self, user: impl IntoAddress) -> DelegatorHistoryResponse;
+    fn get_delegator_rewards(&self, user: impl IntoAddress) -> DelegatorRewardsResponse;
+    fn get_delegator_summary(&self, user: impl IntoAddress) -> DelegatorSummaryResponse;
+    fn get_frontend_open_orders(&self, user: impl IntoAddress) -> Vec<FrontendOpenOrdersResponse>;
+    fn get_user_funding(&self, user: impl IntoAddress, start_time: u64, end_time: Option<u64>) -> Vec<UserFunding>;
+    fn get_funding_history(&self, coin: &str, start_time: u64, end_time: Option<u64>) -> Vec<FundingHistory>;
+    fn get_predicted_fundings(&self) -> PredictedFundingsResponse;
+    fn get_historical_orders(&self, user: impl IntoAddress) -> Vec<HistoricalOrdersResponse>;
+    fn get_l2_book(&self, coin: &str) -> L2BookResponse;
+    fn get_user_non_funding_ledger_updates(&self, user: impl IntoAddress, start_time: u64, end_time: Option<u64>) -> Vec<LedgerUpdate>;
+    fn get_max_builder_fee(&self, user: impl IntoAddress, builder: impl IntoAddress) -> MaxBuilderFeeResponse;
+    fn get_meta(&self, dex: Option<&str>) -> Meta;
+    fn get_meta_and_asset_ctxs(&self, dex: Option<&str>) -> MetaAndAssetCtxsResponse;
+    fn get_open_orders(&self, user: impl IntoAddress) -> Vec<OpenOrdersResponse>;
+    fn get_order_status(&self, user: impl IntoAddress, oid: u64) -> OrderStatusResponse;
+    fn get_portfolio(&self, user: impl IntoAddress) -> PortfolioResponse;
+    fn get_referral_info(&self, user: impl IntoAddress) -> ReferralResponse;
+    fn get_spot_meta(&self) -> SpotMeta;
+    fn get_spot_meta_and_asset_ctxs(&self) -> SpotMetaAndAssetCtxsResponse;
+    fn get_sub_accounts(&self, user: impl IntoAddress) -> SubAccountsResponse;
+    fn get_user_fees(&self, user: impl IntoAddress) -> UserFeesResponse;
+    fn get_user_fills(&self, user: impl IntoAddress) -> Vec<UserFillsResponse>;
+    fn get_user_fills_by_time(&self, user: impl IntoAddress, start_time: u64, end_time: Option<u64>, aggregated_by_time: bool) -> Vec<UserFillsByTimeResponse>;
+    fn get_user_rate_limit(&self, user: impl IntoAddress) -> UserRateLimitResponse;
+    fn get_user_role(&self, user: impl IntoAddress) -> UserRoleResponse;
+    fn get_user_twap_slice_fills(&self, user: impl IntoAddress) -> Vec<UserTwapSliceFillsResponse>;
+    fn get_user_vault_equities(&self, user: impl IntoAddress) -> UserVaultEquitiesResponse;
+    fn get_vault_details(&self, vault_address: impl IntoAddress) -> VaultDetailsResponse;
+}
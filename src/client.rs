@@ -11,8 +11,16 @@
 //! - Comprehensive error handling
 
 use reqwest;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::net::SocketAddr;
 use std::sync::Arc;
+use crate::address::{Address, IntoAddress};
+use crate::action_budget::ActionBudget;
 use crate::config::HyperLiquidConfig;
+use crate::errors::{HyperLiquidError, Result};
+use crate::exchange::signing::Wallet;
+use crate::recording::RecordingTransport;
+use crate::transport::{ReqwestTransport, Transport};
 
 /// Main client for interacting with the HyperLiquid API
 /// 
@@ -36,16 +44,25 @@ use crate::config::HyperLiquidConfig;
 /// let config = HyperLiquidConfig::new()
 ///     .with_timeout(Duration::from_secs(30))
 ///     .with_max_retries(5);
-/// let client = HyperLiquidClient::with_config(config);
+/// let client = HyperLiquidClient::with_config(config)?;
+/// # Ok::<(), hyperliquid::errors::HyperLiquidError>(())
 /// ```
 #[derive(Clone)]
 pub struct HyperLiquidClient {
-    /// HTTP client with connection pooling
-    pub client: reqwest::Client,
+    /// Transport every REST request is sent through; a [`ReqwestTransport`] over a pooled
+    /// HTTP client, wrapped in a [`RecordingTransport`] if the configuration has a
+    /// `record_dir`, unless replaced with [`HyperLiquidClient::with_transport`]
+    pub transport: Arc<dyn Transport>,
     /// Base URL for the HyperLiquid API
     pub base_url: String,
     /// Configuration settings (shared across clones)
     pub config: Arc<HyperLiquidConfig>,
+    /// Wallet used to sign `/exchange` actions
+    pub wallet: Option<Wallet>,
+    /// Vault or sub-account address that signed actions are performed on behalf of
+    pub vault_address: Option<Address>,
+    /// Address-based action budget that `/exchange` actions are counted against
+    pub action_budget: Option<Arc<ActionBudget>>,
 }
 
 impl HyperLiquidClient {
@@ -64,8 +81,13 @@ impl HyperLiquidClient {
     /// 
     /// let client = HyperLiquidClient::new();
     /// ```
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the TLS backend cannot be initialized; use
+    /// [`HyperLiquidClient::with_config`] to handle that as an error.
     pub fn new() -> Self {
-        Self::with_config(HyperLiquidConfig::default())
+        Self::with_config(HyperLiquidConfig::default()).expect("Failed to create HTTP client")
     }
     
     /// Create a new HyperLiquid client with custom configuration
@@ -74,6 +96,11 @@ impl HyperLiquidClient {
     /// 
     /// * `config` - Custom configuration for the client
     /// 
+    /// # Errors
+    /// 
+    /// Returns [`HyperLiquidError::ConfigError`] if a default header, root certificate or
+    /// proxy is invalid, or the HTTP client cannot be created.
+    /// 
     /// # Examples
     /// 
     /// ```rust
@@ -83,35 +110,137 @@ impl HyperLiquidClient {
     /// 
     /// let config = HyperLiquidConfig::new()
     ///     .with_timeout(Duration::from_secs(60))
-    ///     .with_max_retries(5);
+    ///     .with_max_retries(5)
+    ///     .with_default_header("X-Desk", "arb");
     ///     
-    /// let client = HyperLiquidClient::with_config(config);
+    /// let client = HyperLiquidClient::with_config(config)?;
+    /// # Ok::<(), hyperliquid::errors::HyperLiquidError>(())
     /// ```
-    pub fn with_config(config: HyperLiquidConfig) -> Self {
+    pub fn with_config(config: HyperLiquidConfig) -> Result<Self> {
         let mut client_builder = reqwest::Client::builder()
             .timeout(config.timeout)
             .user_agent(&config.user_agent)
             .pool_max_idle_per_host(config.pool_max_idle_per_host)
             .pool_idle_timeout(config.pool_idle_timeout)
             .tcp_keepalive(config.tcp_keepalive)
-            .tcp_nodelay(true);
+            .tcp_nodelay(true)
+            .default_headers(default_headers(&config)?)
+            .local_address(config.local_address);
             
         if config.enable_compression {
             client_builder = client_builder.gzip(true);
         }
         
+        if let Some(proxy) = &config.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .map_err(|e| HyperLiquidError::ConfigError(format!("Invalid proxy '{}': {}", proxy, e)))?;
+            client_builder = client_builder.proxy(proxy);
+        }
+        
+        for path in &config.root_certificates {
+            let invalid = |e: &dyn std::fmt::Display| {
+                HyperLiquidError::ConfigError(format!("Invalid root certificate {}: {}", path.display(), e))
+            };
+            let pem = std::fs::read(path).map_err(|e| invalid(&e))?;
+            let certificate = reqwest::Certificate::from_pem(&pem).map_err(|e| invalid(&e))?;
+            client_builder = client_builder.add_root_certificate(certificate);
+        }
+        
+        if config.http2_prior_knowledge {
+            client_builder = client_builder.http2_prior_knowledge();
+        }
+        
+        // reqwest ignores the port of a resolved address and connects to the URL's
+        for (host, address) in &config.resolve_overrides {
+            client_builder = client_builder.resolve(host, SocketAddr::new(*address, 0));
+        }
+        
         let client = client_builder
             .build()
-            .expect("Failed to create HTTP client");
+            .map_err(|e| HyperLiquidError::ConfigError(format!("Failed to create HTTP client: {}", e)))?;
+            
+        let transport: Arc<dyn Transport> = match &config.record_dir {
+            Some(dir) => Arc::new(RecordingTransport::new(ReqwestTransport::new(client), dir)),
+            None => Arc::new(ReqwestTransport::new(client)),
+        };
             
-        Self {
-            client,
+        Ok(Self {
+            transport,
             base_url: config.base_url.clone(),
             config: Arc::new(config),
-        }
+            wallet: None,
+            vault_address: None,
+            action_budget: None,
+        })
     }
+    
+    /// Send requests through a different transport, e.g. a
+    /// [`crate::transport::MemoryTransport`] in tests
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+    
+    /// Attach a wallet used to sign `/exchange` actions
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use hyperliquid::client::HyperLiquidClient;
+    /// use hyperliquid::exchange::signing::Wallet;
+    /// 
+    /// let wallet = Wallet::from_private_key(
+    ///     "0x0123456789012345678901234567890123456789012345678901234567890123",
+    /// ).unwrap();
+    /// let client = HyperLiquidClient::new().with_wallet(wallet);
+    /// ```
+    pub fn with_wallet(mut self, wallet: Wallet) -> Self {
+        self.wallet = Some(wallet);
+        self
+    }
+    
+    /// Sign actions on behalf of a vault or sub-account
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::errors::HyperLiquidError::InvalidAddress`] if `vault_address` is not
+    /// a valid address.
+    pub fn with_vault_address(mut self, vault_address: impl IntoAddress) -> Result<Self> {
+        self.vault_address = Some(vault_address.into_address()?);
+        Ok(self)
+    }
+
+    /// Count `/exchange` actions against an address's action budget, refusing them before
+    /// the cap is hit
+    pub fn with_action_budget(mut self, action_budget: Arc<ActionBudget>) -> Self {
+        self.action_budget = Some(action_budget);
+        self
+    }
+    
+    /// Create a client from [`HyperLiquidConfig::from_env`]
+    /// 
+    /// # Errors
+    /// 
+    /// Same as [`HyperLiquidConfig::from_env`] and [`HyperLiquidClient::with_config`].
+    pub fn from_env() -> Result<Self> {
+        Self::with_config(HyperLiquidConfig::from_env()?)
+    }
+}
+
+/// Parse the configured default headers
+fn default_headers(config: &HyperLiquidConfig) -> Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &config.default_headers {
+        let invalid = |e: &dyn std::fmt::Display| HyperLiquidError::ConfigError(format!("Invalid header '{}': {}", name, e));
+        let name = HeaderName::try_from(name.as_str()).map_err(|e| invalid(&e))?;
+        let value = HeaderValue::from_str(value).map_err(|e| invalid(&e))?;
+        headers.insert(name, value);
+    }
+    Ok(headers)
+}
 
-    pub fn from_env() -> Self {
-        Self::with_config(HyperLiquidConfig::from_env())
+impl Default for HyperLiquidClient {
+    fn default() -> Self {
+        Self::new()
     }
 }  
\ No newline at end of file
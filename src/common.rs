@@ -1,12 +1,17 @@
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use crate::address::{Address, IntoAddress};
 use crate::client::HyperLiquidClient;
+use crate::errors::{HyperLiquidError, Result};
+use crate::exchange::action::action_batch_len;
+use crate::rate_limiter::{exchange_request_weight, info_items_per_weight, info_request_weight};
+use crate::transport::TransportRequest;
 
 #[derive(Serialize)]
 pub struct BasicUserRequest {
     #[serde(rename = "type")]
     pub request_type: String,
-    pub user: String,
+    pub user: Address,
 }
 
 #[derive(Serialize)]
@@ -15,67 +20,279 @@ pub struct BasicRequest {
     pub request_type: String,
 }
 
+/// What a request costs against the rate limiter
+struct RequestWeight {
+    /// Charged before every attempt
+    base: u32,
+    /// Returned items that cost one extra weight, charged after a successful response
+    items_per_weight: Option<usize>,
+}
+
+impl RequestWeight {
+    fn info(request_type: &str) -> Self {
+        Self {
+            base: info_request_weight(request_type),
+            items_per_weight: info_items_per_weight(request_type),
+        }
+    }
+}
+
 impl HyperLiquidClient {
     /// Internal helper method to execute requests with retry logic
-    async fn execute_with_retry<F, Fut, T>(&self, operation: F) -> anyhow::Result<T>
+    ///
+    /// Requests are sent through the client's [`crate::transport::Transport`]. Retries
+    /// follow [`crate::retry::RetryPolicy`]: retryable statuses and transport errors are
+    /// retried up to `max_retries` times, waiting for `Retry-After` or the jittered backoff
+    /// in between. A retry whose wait would be longer than the policy's `max_delay`, which only
+    /// a `Retry-After` can ask for, or would end past its deadline is not made, and the last
+    /// failure is returned as below; an attempt still in flight when the deadline passes fails
+    /// with `Timeout`. With a rate limiter configured, every attempt first waits for the
+    /// request's weight to be available.
+    ///
+    /// Requests that are not `idempotent`, i.e. signed `/exchange` posts, are never retried
+    /// after they may have reached the server: a response of any status is returned as is,
+    /// and only connection failures, where nothing was sent, are retried. Resending a signed
+    /// action after a 5xx or a timeout could place it twice.
+    ///
+    /// Every attempt runs the configured [`crate::middleware::Middleware`] hooks; `on_error`
+    /// runs once if the call fails. With the `tracing` feature, the call runs in a
+    /// `hyperliquid.request` span and each attempt in a nested `hyperliquid.attempt` span;
+    /// with the `metrics` feature, attempts are recorded in [`crate::metrics::registry`].
+    ///
+    /// Failures are mapped onto [`HyperLiquidError`]: 4xx to `ApiError`, exhausted 429s to
+    /// `RateLimitExceeded`, exhausted 5xx to `ServerError`, timeouts to `Timeout`,
+    /// unreachable hosts to `ConnectionError` and undecodable bodies to `DecodeError`.
+    async fn execute_with_retry<T>(&self, weight: RequestWeight, request: TransportRequest, idempotent: bool) -> Result<T>
     where
-        F: Fn() -> Fut,
-        Fut: std::future::Future<Output = Result<reqwest::Response, reqwest::Error>>,
         T: for<'de> Deserialize<'de>,
     {
-        let mut last_error = None;
-        
-        for attempt in 0..=self.config.max_retries {
-            match operation().await {
-                Ok(response) => {
-                    if response.status().is_success() {
-                        let result: T = response.json().await
-                            .map_err(|e| anyhow::anyhow!("Failed to parse response: {}", e))?;
-                        return Ok(result);
-                    } else if response.status() == 429 {
-                        // Rate limit - wait and retry
-                        if attempt < self.config.max_retries {
-                            let wait_time = Duration::from_millis(1000 * (2_u64.pow(attempt)));
-                            tokio::time::sleep(wait_time).await;
-                            continue;
-                        } else {
-                            return Err(anyhow::anyhow!("Rate limit exceeded after {} retries", self.config.max_retries));
-                        }
-                    } else if response.status().is_server_error() {
-                        // Server errors (5xx) should be retried
-                        if attempt < self.config.max_retries {
-                            let wait_time = Duration::from_millis(1000 * (2_u64.pow(attempt)));
-                            tokio::time::sleep(wait_time).await;
+        let send = self.send_with_retry(weight, &request, idempotent);
+        #[cfg(feature = "tracing")]
+        let send = tracing::Instrument::instrument(send, self.request_span(&request));
+
+        let result = send.await;
+        if let Err(error) = &result {
+            for middleware in &self.config.middleware {
+                middleware.on_error(&request, error);
+            }
+        }
+        result
+    }
+
+    /// Retry loop of [`HyperLiquidClient::execute_with_retry`], running the middleware
+    /// hooks of every attempt
+    async fn send_with_retry<T>(&self, weight: RequestWeight, request: &TransportRequest, idempotent: bool) -> Result<T>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let policy = &self.config.retry_policy;
+        let started = Instant::now();
+        let mut attempt = 0;
+        #[cfg(feature = "metrics")]
+        let (metrics, request_type) = (
+            crate::metrics::registry(),
+            request.request_type().unwrap_or_else(|| "unknown".to_string()),
+        );
+
+        loop {
+            if let Some(limiter) = &self.config.rate_limiter {
+                limiter.acquire(weight.base).await;
+            }
+
+            let prepared;
+            let request = if self.config.middleware.is_empty() {
+                request
+            } else {
+                let mut attempt_request = request.clone();
+                for middleware in &self.config.middleware {
+                    middleware.before_send(&mut attempt_request);
+                }
+                prepared = attempt_request;
+                &prepared
+            };
+
+            #[cfg(feature = "tracing")]
+            let attempt_span = tracing::info_span!(
+                "hyperliquid.attempt",
+                attempt,
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                response_bytes = tracing::field::Empty,
+                backoff_ms = tracing::field::Empty,
+            );
+            let send = self.transport.send(request);
+            #[cfg(feature = "tracing")]
+            let send = tracing::Instrument::instrument(send, attempt_span.clone());
+
+            let sent = Instant::now();
+            let result = match policy.deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_sub(started.elapsed());
+                    tokio::time::timeout(remaining, send)
+                        .await
+                        .map_err(|_| HyperLiquidError::Timeout)?
+                }
+                None => send.await,
+            };
+
+            match result {
+                Ok(mut response) => {
+                    let latency = sent.elapsed();
+                    for middleware in &self.config.middleware {
+                        middleware.after_receive(request, &mut response, latency);
+                    }
+                    #[cfg(feature = "tracing")]
+                    {
+                        attempt_span.record("status", response.status);
+                        attempt_span.record("latency_ms", latency.as_millis() as u64);
+                        attempt_span.record("response_bytes", response.body.len());
+                    }
+                    #[cfg(feature = "metrics")]
+                    metrics.request(&request_type, response.status, latency);
+
+                    let status = response.status;
+                    if (200..300).contains(&status) {
+                        self.charge_items(&weight, &response.body);
+                        return serde_json::from_str(&response.body).map_err(|e| {
+                            #[cfg(feature = "metrics")]
+                            metrics.decode_error(&request_type);
+                            HyperLiquidError::decode(e, &response.body)
+                        });
+                    }
+
+                    if idempotent && policy.is_retryable_status(status) && attempt < self.config.max_retries {
+                        let retry_after = response
+                            .headers
+                            .get(reqwest::header::RETRY_AFTER)
+                            .and_then(|value| value.to_str().ok());
+                        let delay = policy
+                            .delay(attempt, retry_after)
+                            .filter(|delay| self.within_deadline(started, *delay));
+                        if let Some(delay) = delay {
+                            for middleware in &self.config.middleware {
+                                middleware.on_retry(request, attempt + 1, delay);
+                            }
+                            #[cfg(feature = "tracing")]
+                            {
+                                attempt_span.record("backoff_ms", delay.as_millis() as u64);
+                                tracing::warn!(parent: &attempt_span, status, backoff_ms = delay.as_millis() as u64, "retrying request");
+                            }
+                            #[cfg(feature = "metrics")]
+                            metrics.retry(&request_type);
+                            tokio::time::sleep(delay).await;
+                            attempt += 1;
                             continue;
-                        } else {
-                            let status = response.status();
-                            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-                            return Err(anyhow::anyhow!("Server error after {} retries: {} - {}", self.config.max_retries, status, error_text));
                         }
-                    } else {
-                        // Client errors (4xx) should NOT be retried
-                        let status = response.status();
-                        let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
-                        return Err(anyhow::anyhow!("Client error: {} - {}", status, error_text));
                     }
+
+                    let message = response.body;
+                    return Err(if status == 429 {
+                        HyperLiquidError::RateLimitExceeded { retries: attempt }
+                    } else if (500..600).contains(&status) {
+                        HyperLiquidError::ServerError { status, message, retries: attempt }
+                    } else {
+                        HyperLiquidError::ApiError { status, message }
+                    });
                 }
-                Err(e) => {
-                    last_error = Some(e);
-                    if attempt < self.config.max_retries {
-                        let wait_time = Duration::from_millis(500 * (2_u64.pow(attempt)));
-                        tokio::time::sleep(wait_time).await;
-                        continue;
+                Err(error) => {
+                    #[cfg(feature = "metrics")]
+                    metrics.request_error(&request_type);
+                    let unsent = matches!(error, HyperLiquidError::ConnectionError { .. });
+                    if policy.retry_transport_errors && (idempotent || unsent) && attempt < self.config.max_retries {
+                        let delay = policy
+                            .delay(attempt, None)
+                            .filter(|delay| self.within_deadline(started, *delay));
+                        if let Some(delay) = delay {
+                            for middleware in &self.config.middleware {
+                                middleware.on_retry(request, attempt + 1, delay);
+                            }
+                            #[cfg(feature = "tracing")]
+                            {
+                                attempt_span.record("backoff_ms", delay.as_millis() as u64);
+                                tracing::warn!(parent: &attempt_span, error = %error, backoff_ms = delay.as_millis() as u64, "retrying request");
+                            }
+                            #[cfg(feature = "metrics")]
+                            metrics.retry(&request_type);
+                            tokio::time::sleep(delay).await;
+                            attempt += 1;
+                            continue;
+                        }
                     }
+                    return Err(match error {
+                        HyperLiquidError::ConnectionError { message, .. } => {
+                            HyperLiquidError::ConnectionError { message, retries: attempt }
+                        }
+                        error => error,
+                    });
                 }
             }
         }
-        
-        Err(anyhow::anyhow!("Request failed after {} retries: {}", 
-            self.config.max_retries, 
-            last_error.map(|e| e.to_string()).unwrap_or_else(|| "Unknown error".to_string())))
     }
+
+    /// Span covering one request and all of its attempts
+    #[cfg(feature = "tracing")]
+    fn request_span(&self, request: &TransportRequest) -> tracing::Span {
+        let body: serde_json::Value = serde_json::from_str(&request.body).unwrap_or_default();
+        let request_type = body["type"].as_str().or_else(|| body["action"]["type"].as_str()).unwrap_or("unknown");
+        let user = body["user"].as_str().map(|user| {
+            if self.config.trace_hashed_users {
+                hex::encode(&crate::exchange::signing::keccak256(user.to_lowercase().as_bytes())[..8])
+            } else {
+                user.to_string()
+            }
+        });
+
+        tracing::info_span!(
+            "hyperliquid.request",
+            request_type,
+            path = request.path(),
+            user = user.as_deref(),
+            payload_bytes = request.body.len(),
+        )
+    }
+
+    /// Charge the per-item weight of a response whose weight grows with its length
+    fn charge_items(&self, weight: &RequestWeight, body: &str) {
+        let (Some(limiter), Some(items_per_weight)) = (&self.config.rate_limiter, weight.items_per_weight) else {
+            return;
+        };
+        if let Ok(serde_json::Value::Array(items)) = serde_json::from_str(body) {
+            limiter.charge((items.len() / items_per_weight) as u32);
+        }
+    }
+
+    /// Weight of a custom `/info` body, read from its `type`
+    fn custom_request_weight<B: Serialize>(&self, body: &B) -> RequestWeight {
+        if self.config.rate_limiter.is_none() {
+            return RequestWeight { base: 0, items_per_weight: None };
+        }
+        let value = serde_json::to_value(body).unwrap_or_default();
+        RequestWeight::info(value["type"].as_str().unwrap_or_default())
+    }
+
+    /// Weight of a signed `/exchange` body, growing with the number of batched orders,
+    /// cancels or modifies
+    fn exchange_request_weight<B: Serialize>(&self, body: &B) -> RequestWeight {
+        if self.config.rate_limiter.is_none() {
+            return RequestWeight { base: 0, items_per_weight: None };
+        }
+        let value = serde_json::to_value(body).unwrap_or_default();
+        RequestWeight {
+            base: exchange_request_weight(action_batch_len(&value["action"])),
+            items_per_weight: None,
+        }
+    }
+
+    /// Whether waiting `delay` before the next attempt still leaves time under the deadline
+    fn within_deadline(&self, started: Instant, delay: Duration) -> bool {
+        match self.config.retry_policy.deadline {
+            Some(deadline) => started.elapsed() + delay < deadline,
+            None => true,
+        }
+    }
+
     /// Generic method for making POST requests to the /info endpoint with user parameter
-    pub async fn make_user_request<T>(&self, request_type: &str, user: &str) -> anyhow::Result<T>
+    pub async fn make_user_request<T>(&self, request_type: &str, user: impl IntoAddress) -> Result<T>
     where
         T: for<'de> Deserialize<'de>,
     {
@@ -83,20 +300,15 @@ impl HyperLiquidClient {
         
         let request_body = BasicUserRequest {
             request_type: request_type.to_string(),
-            user: user.to_string(),
+            user: user.into_address()?,
         };
 
-        self.execute_with_retry(|| {
-            self.client
-                .post(&url)
-                .header("Content-Type", "application/json")
-                .json(&request_body)
-                .send()
-        }).await
+        let request = TransportRequest::new(url, serde_json::to_string(&request_body)?);
+        self.execute_with_retry(RequestWeight::info(request_type), request, true).await
     }
     
     /// Generic method for making POST requests to the /info endpoint without user parameter
-    pub async fn make_basic_request<T>(&self, request_type: &str) -> anyhow::Result<T>
+    pub async fn make_basic_request<T>(&self, request_type: &str) -> Result<T>
     where
         T: for<'de> Deserialize<'de>,
     {
@@ -106,29 +318,34 @@ impl HyperLiquidClient {
             request_type: request_type.to_string(),
         };
 
-        self.execute_with_retry(|| {
-            self.client
-                .post(&url)
-                .header("Content-Type", "application/json")
-                .json(&request_body)
-                .send()
-        }).await
+        let request = TransportRequest::new(url, serde_json::to_string(&request_body)?);
+        self.execute_with_retry(RequestWeight::info(request_type), request, true).await
     }
     
     /// Generic method for making custom POST requests with custom body
-    pub async fn make_custom_request<B, T>(&self, body: &B) -> anyhow::Result<T>
+    pub async fn make_custom_request<B, T>(&self, body: &B) -> Result<T>
     where
         B: Serialize,
         T: for<'de> Deserialize<'de>,
     {
         let url = format!("{}/info", self.base_url);
 
-        self.execute_with_retry(|| {
-            self.client
-                .post(&url)
-                .header("Content-Type", "application/json")
-                .json(body)
-                .send()
-        }).await
+        let request = TransportRequest::new(url, serde_json::to_string(body)?);
+        self.execute_with_retry(self.custom_request_weight(body), request, true).await
+    }
+    
+    /// Generic method for making signed POST requests to the /exchange endpoint
+    ///
+    /// Only connection failures are retried; see
+    /// [`HyperLiquidClient::execute_with_retry`].
+    pub async fn make_exchange_request<B, T>(&self, body: &B) -> Result<T>
+    where
+        B: Serialize,
+        T: for<'de> Deserialize<'de>,
+    {
+        let url = format!("{}/exchange", self.base_url);
+
+        let request = TransportRequest::new(url, serde_json::to_string(body)?);
+        self.execute_with_retry(self.exchange_request_weight(body), request, false).await
     }
 }
\ No newline at end of file
@@ -1,28 +1,120 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::errors::{HyperLiquidError, Result};
+use crate::middleware::Middleware;
+use crate::network::Network;
+use crate::rate_limiter::RateLimiter;
+use crate::retry::RetryPolicy;
+
+/// REST base URL of the HyperLiquid mainnet API
+pub const MAINNET_API_URL: &str = "https://api.hyperliquid.xyz";
+
+/// REST base URL of the HyperLiquid testnet API
+pub const TESTNET_API_URL: &str = "https://api.hyperliquid-testnet.xyz";
+
+/// WebSocket URL of the HyperLiquid mainnet API
+pub const MAINNET_WS_URL: &str = "wss://api.hyperliquid.xyz/ws";
+
+/// WebSocket URL of the HyperLiquid testnet API
+pub const TESTNET_WS_URL: &str = "wss://api.hyperliquid-testnet.xyz/ws";
+
 #[derive(Debug, Clone)]
 pub struct HyperLiquidConfig {
+    /// Deployment actions are signed for; set together with `base_url` and `ws_url` by
+    /// [`HyperLiquidConfig::with_network`]
+    pub network: Network,
     pub base_url: String,
     pub timeout: Duration,
     pub max_retries: u32,
+    /// Which failures are retried and how long to wait in between
+    pub retry_policy: RetryPolicy,
+    /// Throttles requests to the per-IP weight budget; shared by every client built from
+    /// this configuration (`None` disables throttling)
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    /// Hooks run around every REST request, in order
+    pub middleware: Vec<Arc<dyn Middleware>>,
+    /// Directory every `/info` request and its response are written to as JSON fixtures,
+    /// see [`crate::recording`]
+    pub record_dir: Option<PathBuf>,
+    /// Record a hash of the `user` in request spans instead of the address itself
+    #[cfg(feature = "tracing")]
+    pub trace_hashed_users: bool,
     pub enable_compression: bool,
     pub user_agent: String,
     pub pool_max_idle_per_host: usize,
     pub pool_idle_timeout: Duration,
     pub tcp_keepalive: Duration,
+    /// Proxy every REST request and WebSocket connection goes through, e.g.
+    /// `http://proxy:3128` or `socks5h://proxy:1080`
+    pub proxy: Option<String>,
+    /// Headers sent with every REST request
+    pub default_headers: BTreeMap<String, String>,
+    /// PEM files with root certificates trusted in addition to the system ones
+    pub root_certificates: Vec<PathBuf>,
+    /// Speak HTTP/2 without negotiating it first
+    pub http2_prior_knowledge: bool,
+    /// Local IP address outgoing connections are bound to
+    pub local_address: Option<IpAddr>,
+    /// Fixed IP addresses used instead of DNS for these hosts; the port comes from the URL
+    pub resolve_overrides: BTreeMap<String, IpAddr>,
+    pub ws_url: String,
+    /// Interval between `ping` heartbeats on an idle WebSocket
+    pub ws_ping_interval: Duration,
+    /// A WebSocket that receives nothing for this long is considered dead and reconnected
+    pub ws_stale_timeout: Duration,
+    /// Delay before the first reconnect attempt; doubled on every further attempt
+    pub ws_reconnect_base_delay: Duration,
+    /// Upper bound for the reconnect delay
+    pub ws_reconnect_max_delay: Duration,
+    /// Give up after this many consecutive failed reconnect attempts (`None` retries forever)
+    pub ws_max_reconnect_attempts: Option<u32>,
+    /// How long a `post` request over the WebSocket waits for its response
+    pub ws_post_timeout: Duration,
+    /// Incoming WebSocket messages buffered for a slow reader before further ones are
+    /// dropped and reported as [`crate::ws::message::WsMessage::Lagged`]
+    pub ws_message_buffer: usize,
 }
 
 impl Default for HyperLiquidConfig {
     fn default() -> Self {
         Self {
-            base_url: "https://api.hyperliquid.xyz".to_string(),
+            network: Network::Mainnet,
+            base_url: MAINNET_API_URL.to_string(),
             timeout: Duration::from_secs(30),
             max_retries: 3,
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            middleware: Vec::new(),
+            record_dir: None,
+            #[cfg(feature = "tracing")]
+            trace_hashed_users: false,
             enable_compression: true,
             user_agent: format!("hyperliquid-rust-client/{}", env!("CARGO_PKG_VERSION")),
             pool_max_idle_per_host: 10,
             pool_idle_timeout: Duration::from_secs(90),
             tcp_keepalive: Duration::from_secs(60),
+            proxy: None,
+            default_headers: BTreeMap::new(),
+            root_certificates: Vec::new(),
+            http2_prior_knowledge: false,
+            local_address: None,
+            resolve_overrides: BTreeMap::new(),
+            ws_url: MAINNET_WS_URL.to_string(),
+            ws_ping_interval: Duration::from_secs(30),
+            ws_stale_timeout: Duration::from_secs(60),
+            ws_reconnect_base_delay: Duration::from_millis(500),
+            ws_reconnect_max_delay: Duration::from_secs(30),
+            ws_max_reconnect_attempts: None,
+            ws_post_timeout: Duration::from_secs(10),
+            ws_message_buffer: 1024,
         }
     }
 }
@@ -32,8 +124,33 @@ impl HyperLiquidConfig {
         Self::default()
     }
     
+    /// Use `network`'s REST URL, WebSocket URL and signing constants
+    pub fn with_network(mut self, network: Network) -> Self {
+        self.base_url = network.base_url().to_string();
+        self.ws_url = network.ws_url();
+        self.network = network;
+        self
+    }
+    
+    /// Send REST requests to `base_url`
+    /// 
+    /// The public mainnet and testnet URLs select their presets, including the WebSocket
+    /// URL. Any other URL, e.g. a proxy, keeps signing for the network configured so far
+    /// (mainnet by default); call [`HyperLiquidConfig::with_network`] first to choose
+    /// another one.
     pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
         self.base_url = base_url.into();
+        self.network = match Network::from_base_url(&self.base_url, &self.ws_url) {
+            Network::Custom { base_url, ws_url, .. } => Network::Custom {
+                base_url,
+                ws_url,
+                mainnet: self.network.is_mainnet(),
+            },
+            preset => {
+                self.ws_url = preset.ws_url();
+                preset
+            }
+        };
         self
     }
     
@@ -47,6 +164,41 @@ impl HyperLiquidConfig {
         self
     }
     
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+    
+    /// Throttle requests with a new rate limiter
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+    
+    /// Throttle requests with a rate limiter shared with other configurations
+    pub fn with_shared_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+    
+    /// Append a middleware to the chain run around every REST request
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+    
+    /// Record every `/info` request and its response as a JSON fixture in `dir`
+    pub fn with_record_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.record_dir = Some(dir.into());
+        self
+    }
+    
+    #[cfg(feature = "tracing")]
+    pub fn with_trace_hashed_users(mut self, trace_hashed_users: bool) -> Self {
+        self.trace_hashed_users = trace_hashed_users;
+        self
+    }
+    
     pub fn with_compression(mut self, enable_compression: bool) -> Self {
         self.enable_compression = enable_compression;
         self
@@ -57,34 +209,446 @@ impl HyperLiquidConfig {
         self
     }
     
+    /// Send REST requests and the WebSocket through an HTTP, HTTPS or SOCKS5 proxy
+    pub fn with_proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.proxy = Some(proxy.into());
+        self
+    }
+    
+    /// Send a header with every REST request, replacing any earlier value
+    pub fn with_default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.insert(name.into(), value.into());
+        self
+    }
+    
+    /// Trust the root certificates in a PEM file
+    pub fn with_root_certificate(mut self, path: impl Into<PathBuf>) -> Self {
+        self.root_certificates.push(path.into());
+        self
+    }
+    
+    pub fn with_http2_prior_knowledge(mut self, http2_prior_knowledge: bool) -> Self {
+        self.http2_prior_knowledge = http2_prior_knowledge;
+        self
+    }
+    
+    pub fn with_local_address(mut self, local_address: IpAddr) -> Self {
+        self.local_address = Some(local_address);
+        self
+    }
+    
+    /// Connect to `address` for `host` instead of resolving it, e.g. to pin the API to a
+    /// nearby IP; the port is taken from the URL
+    pub fn with_resolve(mut self, host: impl Into<String>, address: IpAddr) -> Self {
+        self.resolve_overrides.insert(host.into(), address);
+        self
+    }
+    
+    pub fn with_ws_url(mut self, ws_url: impl Into<String>) -> Self {
+        self.ws_url = ws_url.into();
+        self
+    }
+    
+    pub fn with_ws_ping_interval(mut self, ping_interval: Duration) -> Self {
+        self.ws_ping_interval = ping_interval;
+        self
+    }
+    
+    pub fn with_ws_stale_timeout(mut self, stale_timeout: Duration) -> Self {
+        self.ws_stale_timeout = stale_timeout;
+        self
+    }
+    
+    pub fn with_ws_reconnect_backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.ws_reconnect_base_delay = base_delay;
+        self.ws_reconnect_max_delay = max_delay;
+        self
+    }
+    
+    pub fn with_ws_max_reconnect_attempts(mut self, max_attempts: Option<u32>) -> Self {
+        self.ws_max_reconnect_attempts = max_attempts;
+        self
+    }
+    
+    pub fn with_ws_post_timeout(mut self, post_timeout: Duration) -> Self {
+        self.ws_post_timeout = post_timeout;
+        self
+    }
+    
+    pub fn with_ws_message_buffer(mut self, message_buffer: usize) -> Self {
+        self.ws_message_buffer = message_buffer;
+        self
+    }
+    
+    /// Whether this configuration targets mainnet
+    /// 
+    /// Determines the phantom agent source used when signing exchange actions.
+    pub fn is_mainnet(&self) -> bool {
+        self.network.is_mainnet()
+    }
+    
+    /// Check that `base_url` and `ws_url` point at the network actions are signed for
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`HyperLiquidError::SigningError`] if `base_url` or `ws_url` is the public URL
+    /// of the other network, e.g. mainnet signing against the testnet API.
+    pub fn validate_network(&self) -> Result<()> {
+        let url_network = Network::from_base_url(&self.base_url, &self.ws_url);
+        if matches!(url_network, Network::Mainnet | Network::Testnet) && url_network.is_mainnet() != self.is_mainnet() {
+            return Err(HyperLiquidError::SigningError(format!(
+                "Configured for {} but {} is the {} API",
+                self.network, self.base_url, url_network
+            )));
+        }
+        let ws_network = match self.ws_url.trim_end_matches('/') {
+            MAINNET_WS_URL => Some(Network::Mainnet),
+            TESTNET_WS_URL => Some(Network::Testnet),
+            _ => None,
+        };
+        if let Some(ws_network) = ws_network.filter(|ws_network| ws_network.is_mainnet() != self.is_mainnet()) {
+            return Err(HyperLiquidError::SigningError(format!(
+                "Configured for {} but {} is the {} WebSocket",
+                self.network, self.ws_url, ws_network
+            )));
+        }
+        Ok(())
+    }
+    
     /// Load configuration from environment variables
-    pub fn from_env() -> Self {
+    /// 
+    /// Every setting with a single value has a `HYPERLIQUID_*` variable named after it,
+    /// e.g. `HYPERLIQUID_TIMEOUT_SECS` or `HYPERLIQUID_PROXY`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`HyperLiquidError::ConfigError`] naming the variable whose value cannot be
+    /// parsed, or the setting that fails [`HyperLiquidConfig::validate`].
+    pub fn from_env() -> Result<Self> {
+        let config = Self::default().apply(ConfigLayer::from_env()?);
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Load configuration from a TOML or JSON file, then environment variables
+    /// 
+    /// Settings at the top level of the file apply to every profile. If `HYPERLIQUID_PROFILE`
+    /// is set, that profile's settings are layered on top; see
+    /// [`HyperLiquidConfig::from_file_profile`]. Builder methods called on the result take
+    /// precedence over both.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`HyperLiquidError::ConfigError`] if the file cannot be read or parsed, has
+    /// unknown settings or invalid values, or the profile does not exist.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let profile = std::env::var("HYPERLIQUID_PROFILE").ok();
+        Self::load(path.as_ref(), profile.as_deref())
+    }
+    
+    /// Load configuration from a file with a named profile, then environment variables
+    /// 
+    /// ```toml
+    /// timeout_secs = 10
+    /// 
+    /// [profiles.prod]
+    /// network = "mainnet"
+    /// max_retries = 5
+    /// 
+    /// [profiles.testnet]
+    /// network = "testnet"
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Same as [`HyperLiquidConfig::from_file`].
+    pub fn from_file_profile(path: impl AsRef<Path>, profile: &str) -> Result<Self> {
+        Self::load(path.as_ref(), Some(profile))
+    }
+    
+    fn load(path: &Path, profile: Option<&str>) -> Result<Self> {
         let mut config = Self::default();
-        
-        if let Ok(base_url) = std::env::var("HYPERLIQUID_BASE_URL") {
-            config.base_url = base_url;
+        for layer in ConfigLayer::from_file(path, profile)? {
+            config = config.apply(layer);
         }
+        let config = config.apply(ConfigLayer::from_env()?);
+        config.validate()?;
+        Ok(config)
+    }
+    
+    /// Check that every setting is usable
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`HyperLiquidError::ConfigError`] describing the first invalid setting, or
+    /// the error of [`HyperLiquidConfig::validate_network`].
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| Err(HyperLiquidError::ConfigError(message));
         
-        if let Ok(timeout_str) = std::env::var("HYPERLIQUID_TIMEOUT_SECS") {
-            if let Ok(timeout_secs) = timeout_str.parse::<u64>() {
-                config.timeout = Duration::from_secs(timeout_secs);
+        if !self.base_url.starts_with("http://") && !self.base_url.starts_with("https://") {
+            return invalid(format!("base_url '{}' must start with http:// or https://", self.base_url));
+        }
+        if !self.ws_url.starts_with("ws://") && !self.ws_url.starts_with("wss://") {
+            return invalid(format!("ws_url '{}' must start with ws:// or wss://", self.ws_url));
+        }
+        if let Some(proxy) = &self.proxy {
+            if !["http://", "https://", "socks5://", "socks5h://"].iter().any(|scheme| proxy.starts_with(scheme)) {
+                return invalid(format!("proxy '{}' must start with http://, https://, socks5:// or socks5h://", proxy));
             }
         }
-        
-        if let Ok(retries_str) = std::env::var("HYPERLIQUID_MAX_RETRIES") {
-            if let Ok(max_retries) = retries_str.parse::<u32>() {
-                config.max_retries = max_retries;
+        if self.timeout.is_zero() {
+            return invalid("timeout_secs must be greater than 0".to_string());
+        }
+        if self.user_agent.trim().is_empty() {
+            return invalid("user_agent must not be empty".to_string());
+        }
+        if self.ws_ping_interval.is_zero() || self.ws_ping_interval >= self.ws_stale_timeout {
+            return invalid(format!(
+                "ws_ping_interval_secs ({}) must be greater than 0 and less than ws_stale_timeout_secs ({})",
+                self.ws_ping_interval.as_secs(),
+                self.ws_stale_timeout.as_secs()
+            ));
+        }
+        if self.ws_reconnect_base_delay > self.ws_reconnect_max_delay {
+            return invalid(format!(
+                "ws_reconnect_base_delay_ms ({}) must not exceed ws_reconnect_max_delay_ms ({})",
+                self.ws_reconnect_base_delay.as_millis(),
+                self.ws_reconnect_max_delay.as_millis()
+            ));
+        }
+        if self.ws_post_timeout.is_zero() {
+            return invalid("ws_post_timeout_secs must be greater than 0".to_string());
+        }
+        if self.ws_message_buffer == 0 {
+            return invalid("ws_message_buffer must be greater than 0".to_string());
+        }
+        self.validate_network()
+    }
+    
+    /// Override the settings present in `layer`
+    fn apply(mut self, layer: ConfigLayer) -> Self {
+        // A base URL on top of an explicit network keeps that network's signing
+        match (layer.network, layer.base_url) {
+            (Some(Network::Local { .. }), Some(base_url)) => self = self.with_network(Network::Local { base_url }),
+            (Some(network), Some(base_url)) => {
+                self = self.with_network(Network::Custom {
+                    base_url,
+                    ws_url: network.ws_url(),
+                    mainnet: network.is_mainnet(),
+                })
             }
+            (Some(network), None) => self = self.with_network(network),
+            (None, Some(base_url)) => self = self.with_base_url(base_url),
+            (None, None) => {}
+        }
+        if let Some(ws_url) = layer.ws_url {
+            if let Network::Custom { ws_url: network_ws_url, .. } = &mut self.network {
+                network_ws_url.clone_from(&ws_url);
+            }
+            self.ws_url = ws_url;
         }
         
-        if let Ok(compression_str) = std::env::var("HYPERLIQUID_ENABLE_COMPRESSION") {
-            config.enable_compression = compression_str.to_lowercase() == "true";
+        if let Some(timeout_secs) = layer.timeout_secs {
+            self.timeout = Duration::from_secs(timeout_secs);
+        }
+        if let Some(max_retries) = layer.max_retries {
+            self.max_retries = max_retries;
+        }
+        if let Some(enable_compression) = layer.enable_compression {
+            self.enable_compression = enable_compression;
+        }
+        if let Some(user_agent) = layer.user_agent {
+            self.user_agent = user_agent;
+        }
+        if let Some(pool_max_idle_per_host) = layer.pool_max_idle_per_host {
+            self.pool_max_idle_per_host = pool_max_idle_per_host;
+        }
+        if let Some(pool_idle_timeout_secs) = layer.pool_idle_timeout_secs {
+            self.pool_idle_timeout = Duration::from_secs(pool_idle_timeout_secs);
+        }
+        if let Some(tcp_keepalive_secs) = layer.tcp_keepalive_secs {
+            self.tcp_keepalive = Duration::from_secs(tcp_keepalive_secs);
+        }
+        if let Some(proxy) = layer.proxy {
+            self.proxy = Some(proxy);
         }
+        self.default_headers.extend(layer.default_headers);
+        self.root_certificates.extend(layer.root_certificates);
+        if let Some(http2_prior_knowledge) = layer.http2_prior_knowledge {
+            self.http2_prior_knowledge = http2_prior_knowledge;
+        }
+        if let Some(local_address) = layer.local_address {
+            self.local_address = Some(local_address);
+        }
+        self.resolve_overrides.extend(layer.resolve_overrides);
+        if let Some(ws_ping_interval_secs) = layer.ws_ping_interval_secs {
+            self.ws_ping_interval = Duration::from_secs(ws_ping_interval_secs);
+        }
+        if let Some(ws_stale_timeout_secs) = layer.ws_stale_timeout_secs {
+            self.ws_stale_timeout = Duration::from_secs(ws_stale_timeout_secs);
+        }
+        if let Some(ws_reconnect_base_delay_ms) = layer.ws_reconnect_base_delay_ms {
+            self.ws_reconnect_base_delay = Duration::from_millis(ws_reconnect_base_delay_ms);
+        }
+        if let Some(ws_reconnect_max_delay_ms) = layer.ws_reconnect_max_delay_ms {
+            self.ws_reconnect_max_delay = Duration::from_millis(ws_reconnect_max_delay_ms);
+        }
+        if let Some(ws_max_reconnect_attempts) = layer.ws_max_reconnect_attempts {
+            self.ws_max_reconnect_attempts = Some(ws_max_reconnect_attempts);
+        }
+        if let Some(ws_post_timeout_secs) = layer.ws_post_timeout_secs {
+            self.ws_post_timeout = Duration::from_secs(ws_post_timeout_secs);
+        }
+        if let Some(ws_message_buffer) = layer.ws_message_buffer {
+            self.ws_message_buffer = ws_message_buffer;
+        }
+        if let Some(record_dir) = layer.record_dir {
+            self.record_dir = Some(record_dir);
+        }
+        self
+    }
+}
+
+/// Settings read from one source: the top level of a file, a profile, or the environment
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigLayer {
+    #[serde(default, deserialize_with = "deserialize_network")]
+    network: Option<Network>,
+    base_url: Option<String>,
+    ws_url: Option<String>,
+    timeout_secs: Option<u64>,
+    max_retries: Option<u32>,
+    enable_compression: Option<bool>,
+    user_agent: Option<String>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout_secs: Option<u64>,
+    tcp_keepalive_secs: Option<u64>,
+    proxy: Option<String>,
+    #[serde(default)]
+    default_headers: BTreeMap<String, String>,
+    #[serde(default)]
+    root_certificates: Vec<PathBuf>,
+    http2_prior_knowledge: Option<bool>,
+    local_address: Option<IpAddr>,
+    #[serde(default)]
+    resolve_overrides: BTreeMap<String, IpAddr>,
+    ws_ping_interval_secs: Option<u64>,
+    ws_stale_timeout_secs: Option<u64>,
+    ws_reconnect_base_delay_ms: Option<u64>,
+    ws_reconnect_max_delay_ms: Option<u64>,
+    ws_max_reconnect_attempts: Option<u32>,
+    ws_post_timeout_secs: Option<u64>,
+    ws_message_buffer: Option<usize>,
+    record_dir: Option<PathBuf>,
+}
+
+fn deserialize_network<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Option<Network>, D::Error> {
+    let network = String::deserialize(deserializer)?;
+    network.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
+impl ConfigLayer {
+    /// The top-level settings of a file followed by those of `profile`
+    fn from_file(path: &Path, profile: Option<&str>) -> Result<Vec<Self>> {
+        let error = |message: String| HyperLiquidError::ConfigError(format!("{}: {}", path.display(), message));
+        
+        let contents = std::fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+        let mut file: serde_json::Map<String, Value> = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => toml::from_str(&contents).map_err(|e| error(e.to_string()))?,
+            Some("json") => serde_json::from_str(&contents).map_err(|e| error(e.to_string()))?,
+            _ => return Err(error("expected a .toml or .json file".to_string())),
+        };
         
-        if let Ok(user_agent) = std::env::var("HYPERLIQUID_USER_AGENT") {
-            config.user_agent = user_agent;
+        let mut profiles = match file.remove("profiles") {
+            Some(Value::Object(profiles)) => profiles,
+            Some(_) => return Err(error("`profiles` must be a table of named profiles".to_string())),
+            None => serde_json::Map::new(),
+        };
+        let mut layers = vec![Self::from_settings(file).map_err(error)?];
+        
+        if let Some(profile) = profile {
+            let Some(settings) = profiles.remove(profile) else {
+                let available: Vec<&String> = profiles.keys().collect();
+                return Err(error(format!("no profile '{}' (available: {:?})", profile, available)));
+            };
+            let Value::Object(settings) = settings else {
+                return Err(error(format!("profile '{}' must be a table", profile)));
+            };
+            layers.push(Self::from_settings(settings).map_err(|e| error(format!("profile '{}': {}", profile, e)))?);
+        }
+        Ok(layers)
+    }
+    
+    /// Deserialize settings, naming the offending setting in errors
+    fn from_settings(settings: serde_json::Map<String, Value>) -> std::result::Result<Self, String> {
+        serde_json::from_value(Value::Object(settings.clone())).map_err(|e: serde_json::Error| {
+            let culprit = settings.iter().find(|(key, value)| {
+                let single = serde_json::Map::from_iter([((*key).clone(), (*value).clone())]);
+                serde_json::from_value::<Self>(Value::Object(single)).is_err()
+            });
+            match culprit {
+                Some((key, _)) if !e.to_string().contains("unknown field") => format!("`{}`: {}", key, e),
+                _ => e.to_string(),
+            }
+        })
+    }
+    
+    /// Settings from `HYPERLIQUID_*` variables, failing on unparsable values
+    fn from_env() -> Result<Self> {
+        fn var<T: FromStr>(name: &str) -> Result<Option<T>>
+        where
+            T::Err: fmt::Display,
+        {
+            let Ok(value) = std::env::var(name) else {
+                return Ok(None);
+            };
+            match value.parse() {
+                Ok(parsed) => Ok(Some(parsed)),
+                Err(e) => Err(HyperLiquidError::ConfigError(format!("{}={:?}: {}", name, value, e))),
+            }
         }
         
-        config
+        Ok(Self {
+            network: var("HYPERLIQUID_NETWORK")?,
+            base_url: var("HYPERLIQUID_BASE_URL")?,
+            ws_url: var("HYPERLIQUID_WS_URL")?,
+            timeout_secs: var("HYPERLIQUID_TIMEOUT_SECS")?,
+            max_retries: var("HYPERLIQUID_MAX_RETRIES")?,
+            enable_compression: var::<Flag>("HYPERLIQUID_ENABLE_COMPRESSION")?.map(|flag| flag.0),
+            user_agent: var("HYPERLIQUID_USER_AGENT")?,
+            pool_max_idle_per_host: var("HYPERLIQUID_POOL_MAX_IDLE_PER_HOST")?,
+            pool_idle_timeout_secs: var("HYPERLIQUID_POOL_IDLE_TIMEOUT_SECS")?,
+            tcp_keepalive_secs: var("HYPERLIQUID_TCP_KEEPALIVE_SECS")?,
+            proxy: var("HYPERLIQUID_PROXY")?,
+            default_headers: BTreeMap::new(),
+            root_certificates: Vec::new(),
+            http2_prior_knowledge: var::<Flag>("HYPERLIQUID_HTTP2_PRIOR_KNOWLEDGE")?.map(|flag| flag.0),
+            local_address: var("HYPERLIQUID_LOCAL_ADDRESS")?,
+            resolve_overrides: BTreeMap::new(),
+            ws_ping_interval_secs: var("HYPERLIQUID_WS_PING_INTERVAL_SECS")?,
+            ws_stale_timeout_secs: var("HYPERLIQUID_WS_STALE_TIMEOUT_SECS")?,
+            ws_reconnect_base_delay_ms: var("HYPERLIQUID_WS_RECONNECT_BASE_DELAY_MS")?,
+            ws_reconnect_max_delay_ms: var("HYPERLIQUID_WS_RECONNECT_MAX_DELAY_MS")?,
+            ws_max_reconnect_attempts: var("HYPERLIQUID_WS_MAX_RECONNECT_ATTEMPTS")?,
+            ws_post_timeout_secs: var("HYPERLIQUID_WS_POST_TIMEOUT_SECS")?,
+            ws_message_buffer: var("HYPERLIQUID_WS_MESSAGE_BUFFER")?,
+            record_dir: var("HYPERLIQUID_RECORD_DIR")?,
+        })
+    }
+}
+
+/// Boolean environment value: `true`/`false`, `1`/`0` or `yes`/`no`, ignoring case
+struct Flag(bool);
+
+impl FromStr for Flag {
+    type Err = String;
+    
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "true" | "1" | "yes" => Ok(Flag(true)),
+            "false" | "0" | "no" => Ok(Flag(false)),
+            _ => Err("expected true or false".to_string()),
+        }
     }
 }
\ No newline at end of file
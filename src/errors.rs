@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,12 +9,21 @@ pub enum HyperLiquidError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
     
+    /// The API rejected the request with a 4xx status
     #[error("API error: {status} - {message}")]
     ApiError { status: u16, message: String },
     
+    /// The API kept failing with a 5xx status
+    #[error("Server error after {retries} retries: {status} - {message}")]
+    ServerError { status: u16, message: String, retries: u32 },
+    
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
     
+    /// A response could not be decoded into the expected type
+    #[error("Failed to parse response: {message} (body: {body})")]
+    DecodeError { message: String, body: String },
+    
     #[error("Invalid Ethereum address: {0}")]
     InvalidAddress(String),
     
@@ -23,36 +33,53 @@ pub enum HyperLiquidError {
     #[error("Invalid time range: start_time ({start}) must be less than end_time ({end})")]
     InvalidTimeRange { start: u64, end: u64 },
     
-    #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    /// The API kept answering with HTTP 429
+    #[error("Rate limit exceeded (HTTP 429) after {retries} retries")]
+    RateLimitExceeded { retries: u32 },
+    
+    /// Sending the action would exceed the address-based action budget
+    #[error("Action budget exhausted: {used}/{cap} requests used, trade {volume_needed} USDC more to unlock")]
+    ActionBudgetExhausted { used: u64, cap: u64, volume_needed: Decimal },
     
     #[error("Timeout occurred")]
     Timeout,
+    
+    /// The API could not be reached
+    #[error("Connection error after {retries} retries: {message}")]
+    ConnectionError { message: String, retries: u32 },
+    
+    #[error("WebSocket error: {0}")]
+    WebSocketError(String),
+    
+    #[error("Signing error: {0}")]
+    SigningError(String),
+    
+    #[error("Exchange error: {0}")]
+    ExchangeError(String),
+    
+    /// A configuration file or environment variable holds an invalid value
+    #[error("Configuration error: {0}")]
+    ConfigError(String),
+}
+
+impl HyperLiquidError {
+    /// Number of characters of a response body kept in [`HyperLiquidError::DecodeError`]
+    pub const BODY_SNIPPET_LEN: usize = 256;
+
+    /// Build a [`HyperLiquidError::DecodeError`] keeping the start of the offending body
+    pub fn decode(error: impl std::fmt::Display, body: &str) -> Self {
+        HyperLiquidError::DecodeError {
+            message: error.to_string(),
+            body: body.chars().take(Self::BODY_SNIPPET_LEN).collect(),
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, HyperLiquidError>;
 
+/// Check that `address` parses as an [`crate::address::Address`]
 pub fn validate_ethereum_address(address: &str) -> Result<()> {
-    if !address.starts_with("0x") {
-        return Err(HyperLiquidError::InvalidAddress(
-            format!("Invalid Ethereum address '{}': must start with '0x'", address)
-        ));
-    }
-    
-    if address.len() != 42 {
-        return Err(HyperLiquidError::InvalidAddress(
-            format!("Invalid Ethereum address '{}': must be 42 characters long, got {} characters", 
-                   address, address.len())
-        ));
-    }
-    
-    if !address[2..].chars().all(|c| c.is_ascii_hexdigit()) {
-        return Err(HyperLiquidError::InvalidAddress(
-            format!("Invalid Ethereum address '{}': contains non-hexadecimal characters", address)
-        ));
-    }
-    
-    Ok(())
+    address.parse::<crate::address::Address>().map(|_| ())
 }
 
 pub fn validate_coin_symbol(coin: &str) -> Result<()> {
@@ -100,6 +127,20 @@ pub fn validate_interval(interval: &str) -> Result<()> {
     Ok(())
 }
 
+pub fn validate_cloid(cloid: &str) -> Result<()> {
+    let is_valid = cloid.len() == 34
+        && (cloid.starts_with("0x") || cloid.starts_with("0X"))
+        && cloid[2..].chars().all(|c| c.is_ascii_hexdigit());
+    
+    if !is_valid {
+        return Err(HyperLiquidError::InvalidInput(
+            format!("Invalid client order id '{}': must be '0x' followed by 32 hexadecimal characters", cloid)
+        ));
+    }
+    
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,7 +149,8 @@ mod tests {
     fn test_ethereum_address_validation() {
         // Valid cases
         assert!(validate_ethereum_address("0x1234567890123456789012345678901234567890").is_ok());
-        assert!(validate_ethereum_address("0xabcdefABCDEF1234567890123456789012345678").is_ok());
+        assert!(validate_ethereum_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_ok());
+        assert!(validate_ethereum_address("0X1234567890123456789012345678901234567890").is_ok());
         assert!(validate_ethereum_address("0x0000000000000000000000000000000000000000").is_ok());
 
         // Invalid cases
@@ -116,7 +158,7 @@ mod tests {
         assert!(validate_ethereum_address("0x123").is_err());
         assert!(validate_ethereum_address("1234567890123456789012345678901234567890").is_err());
         assert!(validate_ethereum_address("0x123456789012345678901234567890123456789G").is_err());
-        assert!(validate_ethereum_address("0X1234567890123456789012345678901234567890").is_err());
+        assert!(validate_ethereum_address("0xabcdefABCDEF1234567890123456789012345678").is_err());
     }
 
     #[test]
@@ -165,6 +207,17 @@ mod tests {
         assert!(validate_time_range(u64::MAX, 0).is_err());
     }
 
+    #[test]
+    fn test_cloid_validation() {
+        assert!(validate_cloid("0x00000000000000000000000000000001").is_ok());
+        assert!(validate_cloid("0xABCDEFabcdef00000000000000000000").is_ok());
+
+        assert!(validate_cloid("").is_err());
+        assert!(validate_cloid("0x1234").is_err());
+        assert!(validate_cloid("00000000000000000000000000000000").is_err());
+        assert!(validate_cloid("0x0000000000000000000000000000000G").is_err());
+    }
+
     #[test]
     fn test_error_display() {
         let err = HyperLiquidError::InvalidAddress("test".to_string());
@@ -173,7 +226,19 @@ mod tests {
         let err = HyperLiquidError::InvalidTimeRange { start: 100, end: 50 };
         assert!(err.to_string().contains("Invalid time range"));
 
-        let err = HyperLiquidError::RateLimitExceeded;
-        assert_eq!(err.to_string(), "Rate limit exceeded");
+        let err = HyperLiquidError::RateLimitExceeded { retries: 3 };
+        assert_eq!(err.to_string(), "Rate limit exceeded (HTTP 429) after 3 retries");
+    }
+
+    #[test]
+    fn test_decode_error_keeps_body_snippet() {
+        let body = "x".repeat(1000);
+        match HyperLiquidError::decode("expected value", &body) {
+            HyperLiquidError::DecodeError { message, body } => {
+                assert_eq!(message, "expected value");
+                assert_eq!(body.len(), HyperLiquidError::BODY_SNIPPET_LEN);
+            }
+            other => panic!("Expected DecodeError, got {:?}", other),
+        }
     }
 }
\ No newline at end of file
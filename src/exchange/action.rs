@@ -0,0 +1,124 @@
+//! Signed request envelope for the `/exchange` endpoint.
+//!
+//! Every trading action is wrapped in an [`ExchangeRequest`] carrying the action itself,
+//! its nonce, the EIP-712 signature and the optional vault address the action is
+//! performed on behalf of.
+
+use serde::{Deserialize, Serialize};
+
+use crate::address::Address;
+use crate::client::HyperLiquidClient;
+use crate::errors::{HyperLiquidError, Result};
+use crate::exchange::signing::{sign_l1_action, Signature};
+
+/// A signed action ready to be posted to `/exchange`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeRequest<A> {
+    pub action: A,
+    pub nonce: u64,
+    pub signature: Signature,
+    pub vault_address: Option<Address>,
+}
+
+/// Top-level `/exchange` response: `{"status": "ok", "response": ...}` or
+/// `{"status": "err", "response": "<message>"}`
+#[derive(Debug, Deserialize)]
+#[serde(tag = "status", content = "response")]
+pub enum ExchangeResponse<T> {
+    #[serde(rename = "ok")]
+    Ok(T),
+    #[serde(rename = "err")]
+    Err(String),
+}
+
+/// Payload of a successful `/exchange` response
+///
+/// `data` is absent for actions that only acknowledge success (`"type": "default"`).
+#[derive(Debug, Deserialize)]
+pub struct ExchangeResponseData<T> {
+    #[serde(rename = "type")]
+    pub response_type: String,
+    pub data: Option<T>,
+}
+
+/// An action that can be posted with [`HyperLiquidClient::post_action`]
+pub trait ExchangeAction: Serialize {
+    /// Requests the action counts as against the address-based action budget: one per
+    /// order, cancel or modify in a batch
+    fn request_count(&self) -> u64;
+}
+
+/// Number of orders, cancels or modifies batched in a serialized action; 0 if not batched
+pub(crate) fn action_batch_len(action: &serde_json::Value) -> usize {
+    ["orders", "cancels", "modifies"]
+        .iter()
+        .find_map(|key| action[key].as_array())
+        .map_or(0, Vec::len)
+}
+
+impl HyperLiquidClient {
+    /// Sign an action with the client's wallet and vault address
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no wallet is configured, the base URL belongs to a different network
+    /// than the one actions are signed for, or the action cannot be encoded.
+    pub fn sign_action<A: Serialize>(&self, action: A) -> Result<ExchangeRequest<A>> {
+        let wallet = self.wallet.as_ref().ok_or_else(|| {
+            HyperLiquidError::SigningError(
+                "No wallet configured: use HyperLiquidClient::with_wallet to sign exchange actions".to_string(),
+            )
+        })?;
+
+        self.config.validate_network()?;
+        let nonce = wallet.next_nonce();
+        let signature = sign_l1_action(
+            wallet,
+            &action,
+            self.vault_address,
+            nonce,
+            &self.config.network,
+        )?;
+
+        Ok(ExchangeRequest {
+            action,
+            nonce,
+            signature,
+            vault_address: self.vault_address,
+        })
+    }
+
+    /// Sign an action and post it to the `/exchange` endpoint
+    ///
+    /// Returns the `response` payload of a successful reply. An `"err"` status from the
+    /// exchange is surfaced as [`HyperLiquidError::ExchangeError`]. With an action budget,
+    /// the signed action is counted against it with [`ExchangeAction::request_count`] and
+    /// refused if it would exceed the cap; the count is given back if the request never
+    /// reached the exchange.
+    pub async fn post_action<A, T>(&self, action: A) -> Result<T>
+    where
+        A: ExchangeAction,
+        T: for<'de> Deserialize<'de>,
+    {
+        let requests = action.request_count();
+        let request = self.sign_action(action)?;
+        if let Some(budget) = &self.action_budget {
+            budget.spend(requests)?;
+        }
+
+        let result = self.make_exchange_request(&request).await;
+        // Signed actions are only resent when the connection could not be opened, so a
+        // connection error means no attempt was sent
+        if let (Some(budget), Err(HyperLiquidError::ConnectionError { .. })) = (&self.action_budget, &result) {
+            budget.refund(requests);
+        }
+
+        let response: ExchangeResponse<T> = result?;
+
+        match response {
+            ExchangeResponse::Ok(data) => Ok(data),
+            ExchangeResponse::Err(message) => Err(HyperLiquidError::ExchangeError(message)),
+        }
+    }
+}
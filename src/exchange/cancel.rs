@@ -0,0 +1,241 @@
+//! Order cancellation by order id or client order id.
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::HyperLiquidClient;
+use crate::errors::{validate_cloid, HyperLiquidError, Result};
+use crate::exchange::action::{ExchangeAction, ExchangeResponseData};
+use crate::info::meta::Meta;
+use crate::types::Order;
+
+/// Cancel a resting order by its exchange-assigned order id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelRequest {
+    /// Asset index of the order
+    pub asset: u32,
+    /// Order id, as returned in `types::Order::oid` or `OpenOrdersResponse::oid`
+    pub oid: u64,
+}
+
+impl CancelRequest {
+    pub fn new(asset: u32, oid: u64) -> Self {
+        Self { asset, oid }
+    }
+
+    /// Cancel an open order, e.g. from `get_frontend_open_orders`, looking its asset up in
+    /// the perp `meta`
+    pub fn from_order(order: &Order, meta: &Meta) -> Result<Self> {
+        Ok(Self::new(order_asset(order, meta)?, order.oid))
+    }
+}
+
+/// Cancel a resting order by the client order id it was placed with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelByCloidRequest {
+    /// Asset index of the order
+    pub asset: u32,
+    /// Client order id, as returned in `types::Order::cloid`
+    pub cloid: String,
+}
+
+impl CancelByCloidRequest {
+    pub fn new(asset: u32, cloid: impl Into<String>) -> Self {
+        Self { asset, cloid: cloid.into() }
+    }
+
+    /// Cancel an open order by the client order id it was placed with, looking its asset up
+    /// in the perp `meta`
+    ///
+    /// Fails with [`HyperLiquidError::InvalidInput`] if the order has no client order id.
+    pub fn from_order(order: &Order, meta: &Meta) -> Result<Self> {
+        let cloid = order.cloid.as_ref().ok_or_else(|| {
+            HyperLiquidError::InvalidInput(format!("Order {} has no client order id", order.oid))
+        })?;
+        Ok(Self::new(order_asset(order, meta)?, cloid.clone()))
+    }
+}
+
+/// Asset id of an open order's coin
+fn order_asset(order: &Order, meta: &Meta) -> Result<u32> {
+    meta.asset_id(&order.coin)
+        .ok_or_else(|| HyperLiquidError::InvalidCoin(format!("Coin '{}' is not listed in meta", order.coin)))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CancelWire {
+    pub a: u32,
+    pub o: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CancelByCloidWire {
+    pub asset: u32,
+    pub cloid: String,
+}
+
+/// The `cancel` action
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkCancel {
+    #[serde(rename = "type")]
+    pub action_type: String,
+    pub cancels: Vec<CancelWire>,
+}
+
+/// The `cancelByCloid` action
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkCancelByCloid {
+    #[serde(rename = "type")]
+    pub action_type: String,
+    pub cancels: Vec<CancelByCloidWire>,
+}
+
+impl BulkCancel {
+    pub fn new(cancels: &[CancelRequest]) -> Result<Self> {
+        if cancels.is_empty() {
+            return Err(HyperLiquidError::InvalidInput("At least one cancel is required".to_string()));
+        }
+
+        Ok(Self {
+            action_type: "cancel".to_string(),
+            cancels: cancels
+                .iter()
+                .map(|cancel| CancelWire { a: cancel.asset, o: cancel.oid })
+                .collect(),
+        })
+    }
+}
+
+impl ExchangeAction for BulkCancel {
+    fn request_count(&self) -> u64 {
+        self.cancels.len() as u64
+    }
+}
+
+impl BulkCancelByCloid {
+    pub fn new(cancels: &[CancelByCloidRequest]) -> Result<Self> {
+        if cancels.is_empty() {
+            return Err(HyperLiquidError::InvalidInput("At least one cancel is required".to_string()));
+        }
+
+        let cancels = cancels
+            .iter()
+            .map(|cancel| {
+                validate_cloid(&cancel.cloid)?;
+                Ok(CancelByCloidWire {
+                    asset: cancel.asset,
+                    cloid: cancel.cloid.to_lowercase(),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            action_type: "cancelByCloid".to_string(),
+            cancels,
+        })
+    }
+}
+
+impl ExchangeAction for BulkCancelByCloid {
+    fn request_count(&self) -> u64 {
+        self.cancels.len() as u64
+    }
+}
+
+/// Per-order outcome of a cancel action
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CancelStatus {
+    Success,
+    Error(String),
+}
+
+/// `data` payload of a cancel response
+#[derive(Debug, Deserialize)]
+pub struct CancelStatuses {
+    pub statuses: Vec<CancelStatus>,
+}
+
+impl HyperLiquidClient {
+    /// Cancel a single order by order id
+    pub async fn cancel_order(&self, asset: u32, oid: u64) -> Result<CancelStatus> {
+        first_status(self.cancel_orders(vec![CancelRequest::new(asset, oid)]).await?)
+    }
+
+    /// Cancel several orders by order id in one signed action
+    ///
+    /// Statuses are returned in the same order as the requests.
+    pub async fn cancel_orders(&self, cancels: Vec<CancelRequest>) -> Result<Vec<CancelStatus>> {
+        let action = BulkCancel::new(&cancels)?;
+        cancel_statuses(self.post_action(action).await?)
+    }
+
+    /// Cancel a single order by client order id
+    pub async fn cancel_order_by_cloid(&self, asset: u32, cloid: &str) -> Result<CancelStatus> {
+        first_status(self.cancel_orders_by_cloid(vec![CancelByCloidRequest::new(asset, cloid)]).await?)
+    }
+
+    /// Cancel several orders by client order id in one signed action
+    pub async fn cancel_orders_by_cloid(
+        &self,
+        cancels: Vec<CancelByCloidRequest>,
+    ) -> Result<Vec<CancelStatus>> {
+        let action = BulkCancelByCloid::new(&cancels)?;
+        cancel_statuses(self.post_action(action).await?)
+    }
+}
+
+fn cancel_statuses(response: ExchangeResponseData<CancelStatuses>) -> Result<Vec<CancelStatus>> {
+    response
+        .data
+        .map(|data| data.statuses)
+        .ok_or_else(|| HyperLiquidError::ExchangeError("Exchange response is missing cancel statuses".to_string()))
+}
+
+fn first_status(statuses: Vec<CancelStatus>) -> Result<CancelStatus> {
+    statuses
+        .into_iter()
+        .next()
+        .ok_or_else(|| HyperLiquidError::ExchangeError("Exchange returned no status for the cancel".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_wire_format() {
+        let action = BulkCancel::new(&[CancelRequest::new(3, 82382)]).unwrap();
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            serde_json::json!({"type": "cancel", "cancels": [{"a": 3, "o": 82382}]})
+        );
+
+        let action =
+            BulkCancelByCloid::new(&[CancelByCloidRequest::new(3, "0x000000000000000000000000000000AB")]).unwrap();
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            serde_json::json!({
+                "type": "cancelByCloid",
+                "cancels": [{"asset": 3, "cloid": "0x000000000000000000000000000000ab"}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_cancels_rejected() {
+        assert!(BulkCancel::new(&[]).is_err());
+        assert!(BulkCancelByCloid::new(&[]).is_err());
+        assert!(BulkCancelByCloid::new(&[CancelByCloidRequest::new(0, "abc")]).is_err());
+    }
+
+    #[test]
+    fn test_cancel_status_parsing() {
+        let data: CancelStatuses = serde_json::from_value(serde_json::json!({
+            "statuses": ["success", {"error": "Order was never placed, already canceled, or filled."}]
+        }))
+        .unwrap();
+
+        assert_eq!(data.statuses[0], CancelStatus::Success);
+        assert!(matches!(&data.statuses[1], CancelStatus::Error(e) if e.contains("never placed")));
+    }
+}
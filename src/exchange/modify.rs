@@ -0,0 +1,197 @@
+//! Modification of resting orders, individually or in batches.
+
+use serde::Serialize;
+
+use crate::client::HyperLiquidClient;
+use crate::errors::{validate_cloid, HyperLiquidError, Result};
+use crate::exchange::action::{ExchangeAction, ExchangeResponseData};
+use crate::exchange::order::{OrderRequest, OrderStatus, OrderStatuses, OrderWire};
+
+/// Identifies an existing order either by order id or by client order id
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum OrderId {
+    Oid(u64),
+    Cloid(String),
+}
+
+impl From<u64> for OrderId {
+    fn from(oid: u64) -> Self {
+        OrderId::Oid(oid)
+    }
+}
+
+impl From<String> for OrderId {
+    fn from(cloid: String) -> Self {
+        OrderId::Cloid(cloid)
+    }
+}
+
+impl From<&str> for OrderId {
+    fn from(cloid: &str) -> Self {
+        OrderId::Cloid(cloid.to_string())
+    }
+}
+
+impl OrderId {
+    fn to_wire(&self) -> Result<OrderId> {
+        match self {
+            OrderId::Oid(oid) => Ok(OrderId::Oid(*oid)),
+            OrderId::Cloid(cloid) => {
+                validate_cloid(cloid)?;
+                Ok(OrderId::Cloid(cloid.to_lowercase()))
+            }
+        }
+    }
+}
+
+/// Replace a resting order with a new order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModifyRequest {
+    /// The order to modify
+    pub oid: OrderId,
+    /// The full replacement order
+    pub order: OrderRequest,
+}
+
+impl ModifyRequest {
+    pub fn new(oid: impl Into<OrderId>, order: OrderRequest) -> Self {
+        Self { oid: oid.into(), order }
+    }
+
+    pub fn to_wire(&self) -> Result<ModifyWire> {
+        Ok(ModifyWire {
+            oid: self.oid.to_wire()?,
+            order: self.order.to_wire()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModifyWire {
+    pub oid: OrderId,
+    pub order: OrderWire,
+}
+
+/// The `modify` action
+#[derive(Debug, Clone, Serialize)]
+pub struct Modify {
+    #[serde(rename = "type")]
+    pub action_type: String,
+    pub oid: OrderId,
+    pub order: OrderWire,
+}
+
+impl Modify {
+    pub fn new(modify: &ModifyRequest) -> Result<Self> {
+        let wire = modify.to_wire()?;
+        Ok(Self {
+            action_type: "modify".to_string(),
+            oid: wire.oid,
+            order: wire.order,
+        })
+    }
+}
+
+impl ExchangeAction for Modify {
+    fn request_count(&self) -> u64 {
+        1
+    }
+}
+
+/// The `batchModify` action
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchModify {
+    #[serde(rename = "type")]
+    pub action_type: String,
+    pub modifies: Vec<ModifyWire>,
+}
+
+impl BatchModify {
+    pub fn new(modifies: &[ModifyRequest]) -> Result<Self> {
+        if modifies.is_empty() {
+            return Err(HyperLiquidError::InvalidInput("At least one modify is required".to_string()));
+        }
+
+        Ok(Self {
+            action_type: "batchModify".to_string(),
+            modifies: modifies.iter().map(ModifyRequest::to_wire).collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl ExchangeAction for BatchModify {
+    fn request_count(&self) -> u64 {
+        self.modifies.len() as u64
+    }
+}
+
+impl HyperLiquidClient {
+    /// Modify a single resting order
+    ///
+    /// The exchange only acknowledges a single modify; a rejection is surfaced as
+    /// [`HyperLiquidError::ExchangeError`]. Use [`HyperLiquidClient::batch_modify_orders`]
+    /// to get typed per-order statuses.
+    pub async fn modify_order(&self, oid: impl Into<OrderId>, order: OrderRequest) -> Result<()> {
+        let action = Modify::new(&ModifyRequest::new(oid, order))?;
+        let _: ExchangeResponseData<serde_json::Value> = self.post_action(action).await?;
+        Ok(())
+    }
+
+    /// Modify several resting orders in one signed action
+    ///
+    /// Statuses are returned in the same order as the requests.
+    pub async fn batch_modify_orders(&self, modifies: Vec<ModifyRequest>) -> Result<Vec<OrderStatus>> {
+        let action = BatchModify::new(&modifies)?;
+        let response: ExchangeResponseData<OrderStatuses> = self.post_action(action).await?;
+
+        response
+            .data
+            .map(|data| data.statuses)
+            .ok_or_else(|| HyperLiquidError::ExchangeError("Exchange response is missing order statuses".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::order::Tif;
+    use rust_decimal::Decimal;
+
+    fn order() -> OrderRequest {
+        OrderRequest::limit(2, false, Decimal::new(1005, 1), Decimal::new(3, 0), Tif::Gtc)
+    }
+
+    #[test]
+    fn test_modify_wire_format() {
+        let action = Modify::new(&ModifyRequest::new(77738308u64, order())).unwrap();
+        assert_eq!(
+            serde_json::to_value(&action).unwrap(),
+            serde_json::json!({
+                "type": "modify",
+                "oid": 77738308,
+                "order": {"a": 2, "b": false, "p": "100.5", "s": "3", "r": false, "t": {"limit": {"tif": "Gtc"}}}
+            })
+        );
+    }
+
+    #[test]
+    fn test_batch_modify_wire_format() {
+        let action = BatchModify::new(&[
+            ModifyRequest::new(1u64, order()),
+            ModifyRequest::new("0x00000000000000000000000000000001", order()),
+        ])
+        .unwrap();
+
+        let value = serde_json::to_value(&action).unwrap();
+        assert_eq!(value["type"], "batchModify");
+        assert_eq!(value["modifies"][0]["oid"], 1);
+        assert_eq!(value["modifies"][1]["oid"], "0x00000000000000000000000000000001");
+    }
+
+    #[test]
+    fn test_invalid_modifies_rejected() {
+        assert!(BatchModify::new(&[]).is_err());
+        assert!(Modify::new(&ModifyRequest::new("bad-cloid", order())).is_err());
+    }
+}
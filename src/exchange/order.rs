@@ -0,0 +1,444 @@
+//! Order placement through the signed `/exchange` endpoint.
+//!
+//! [`OrderRequest`] is the typed, user-facing description of an order. It is converted
+//! into the compact wire format (`a`, `b`, `p`, `s`, `r`, `t`, `c`) expected by the
+//! exchange before being hashed and signed.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::client::HyperLiquidClient;
+use crate::errors::{validate_cloid, HyperLiquidError, Result};
+use crate::exchange::action::{ExchangeAction, ExchangeResponseData};
+
+/// Time in force for limit orders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Tif {
+    /// Add liquidity only (post-only); canceled instead of crossing the book
+    Alo,
+    /// Immediate or cancel
+    Ioc,
+    /// Good til canceled
+    Gtc,
+}
+
+/// Whether a trigger order is a take-profit or a stop-loss
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Tpsl {
+    Tp,
+    Sl,
+}
+
+/// Order type of an [`OrderRequest`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderType {
+    /// Limit order with a time in force
+    Limit { tif: Tif },
+    /// Trigger (take-profit / stop-loss) order
+    Trigger {
+        trigger_px: Decimal,
+        /// Execute as a market order once triggered, otherwise as a limit at `limit_px`
+        is_market: bool,
+        tpsl: Tpsl,
+    },
+}
+
+/// How the orders of a single action relate to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Grouping {
+    /// Independent orders
+    #[serde(rename = "na")]
+    Na,
+    /// TP/SL orders attached to the parent order placed in the same action
+    #[serde(rename = "normalTpsl")]
+    NormalTpsl,
+    /// TP/SL orders attached to the whole position
+    #[serde(rename = "positionTpsl")]
+    PositionTpsl,
+}
+
+/// A typed order to be placed on the exchange
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderRequest {
+    /// Asset index (position in the perpetuals universe, 10000 + spot pair index, or
+    /// 100000 + dex index * 10000 + position for builder-deployed perp dexs)
+    pub asset: u32,
+    pub is_buy: bool,
+    pub limit_px: Decimal,
+    pub sz: Decimal,
+    pub reduce_only: bool,
+    pub order_type: OrderType,
+    /// Client order ID: `0x` followed by 32 hex characters
+    pub cloid: Option<String>,
+}
+
+impl OrderRequest {
+    /// Create a limit order with the given time in force
+    pub fn limit(asset: u32, is_buy: bool, limit_px: Decimal, sz: Decimal, tif: Tif) -> Self {
+        Self {
+            asset,
+            is_buy,
+            limit_px,
+            sz,
+            reduce_only: false,
+            order_type: OrderType::Limit { tif },
+            cloid: None,
+        }
+    }
+
+    /// Create a trigger order
+    pub fn trigger(
+        asset: u32,
+        is_buy: bool,
+        limit_px: Decimal,
+        sz: Decimal,
+        trigger_px: Decimal,
+        is_market: bool,
+        tpsl: Tpsl,
+    ) -> Self {
+        Self {
+            asset,
+            is_buy,
+            limit_px,
+            sz,
+            reduce_only: false,
+            order_type: OrderType::Trigger { trigger_px, is_market, tpsl },
+            cloid: None,
+        }
+    }
+
+    pub fn with_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+
+    pub fn with_cloid(mut self, cloid: impl Into<String>) -> Self {
+        self.cloid = Some(cloid.into());
+        self
+    }
+
+    /// Validate the order and convert it into its wire representation
+    pub fn to_wire(&self) -> Result<OrderWire> {
+        if self.sz <= Decimal::ZERO {
+            return Err(HyperLiquidError::InvalidInput(format!(
+                "Invalid order size '{}': must be positive",
+                self.sz
+            )));
+        }
+        if self.limit_px <= Decimal::ZERO {
+            return Err(HyperLiquidError::InvalidInput(format!(
+                "Invalid limit price '{}': must be positive",
+                self.limit_px
+            )));
+        }
+        if let Some(cloid) = &self.cloid {
+            validate_cloid(cloid)?;
+        }
+
+        let order_type = match &self.order_type {
+            OrderType::Limit { tif } => OrderTypeWire::Limit { tif: *tif },
+            OrderType::Trigger { trigger_px, is_market, tpsl } => OrderTypeWire::Trigger {
+                is_market: *is_market,
+                trigger_px: decimal_to_wire(*trigger_px)?,
+                tpsl: *tpsl,
+            },
+        };
+
+        Ok(OrderWire {
+            a: self.asset,
+            b: self.is_buy,
+            p: decimal_to_wire(self.limit_px)?,
+            s: decimal_to_wire(self.sz)?,
+            r: self.reduce_only,
+            t: order_type,
+            c: self.cloid.as_ref().map(|cloid| cloid.to_lowercase()),
+        })
+    }
+}
+
+/// Format a decimal the way the exchange hashes it: at most 8 decimal places,
+/// no trailing zeros and no exponent
+pub fn decimal_to_wire(value: Decimal) -> Result<String> {
+    let rounded = value.round_dp(8);
+    if rounded != value {
+        return Err(HyperLiquidError::InvalidInput(format!(
+            "Invalid decimal '{}': more than 8 decimal places",
+            value
+        )));
+    }
+
+    let normalized = rounded.normalize();
+    if normalized.is_zero() {
+        return Ok("0".to_string());
+    }
+    Ok(normalized.to_string())
+}
+
+/// Wire representation of an order type; field order matters for hashing
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderTypeWire {
+    Limit {
+        tif: Tif,
+    },
+    #[serde(rename_all = "camelCase")]
+    Trigger {
+        is_market: bool,
+        trigger_px: String,
+        tpsl: Tpsl,
+    },
+}
+
+/// Wire representation of an order; field order matters for hashing
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderWire {
+    pub a: u32,
+    pub b: bool,
+    pub p: String,
+    pub s: String,
+    pub r: bool,
+    pub t: OrderTypeWire,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub c: Option<String>,
+}
+
+/// The `order` action
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkOrder {
+    #[serde(rename = "type")]
+    pub action_type: String,
+    pub orders: Vec<OrderWire>,
+    pub grouping: Grouping,
+}
+
+impl BulkOrder {
+    pub fn new(orders: &[OrderRequest], grouping: Grouping) -> Result<Self> {
+        if orders.is_empty() {
+            return Err(HyperLiquidError::InvalidInput("At least one order is required".to_string()));
+        }
+
+        Ok(Self {
+            action_type: "order".to_string(),
+            orders: orders.iter().map(OrderRequest::to_wire).collect::<Result<_>>()?,
+            grouping,
+        })
+    }
+}
+
+impl ExchangeAction for BulkOrder {
+    fn request_count(&self) -> u64 {
+        self.orders.len() as u64
+    }
+}
+
+/// Per-order outcome of an `order` action
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderStatus {
+    /// The order is resting on the book
+    Resting(RestingOrder),
+    /// The order was (fully or partially) filled immediately
+    Filled(FilledOrder),
+    /// The order was rejected
+    Error(String),
+    /// The order is waiting for its parent to fill (grouped TP/SL)
+    WaitingForFill,
+    /// The order is waiting for its trigger price
+    WaitingForTrigger,
+    /// A status this crate does not model yet
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RestingOrder {
+    pub oid: u64,
+    #[serde(default)]
+    pub cloid: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilledOrder {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub total_sz: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub avg_px: Decimal,
+    pub oid: u64,
+    #[serde(default)]
+    pub cloid: Option<String>,
+}
+
+/// `data` payload of an `order` response
+#[derive(Debug, Deserialize)]
+pub struct OrderStatuses {
+    pub statuses: Vec<OrderStatus>,
+}
+
+impl HyperLiquidClient {
+    /// Place a single order
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use hyperliquid::client::HyperLiquidClient;
+    /// use hyperliquid::exchange::order::{OrderRequest, OrderStatus, Tif};
+    /// use hyperliquid::exchange::signing::Wallet;
+    /// use rust_decimal::Decimal;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> hyperliquid::errors::Result<()> {
+    ///     let wallet = Wallet::from_private_key("0x...")?;
+    ///     let client = HyperLiquidClient::new().with_wallet(wallet);
+    ///
+    ///     // Post-only bid for 0.01 BTC (asset 0) at 50,000
+    ///     let order = OrderRequest::limit(0, true, Decimal::new(50_000, 0), Decimal::new(1, 2), Tif::Alo);
+    ///     match client.place_order(order).await? {
+    ///         OrderStatus::Resting(resting) => println!("Resting with oid {}", resting.oid),
+    ///         OrderStatus::Error(message) => println!("Rejected: {}", message),
+    ///         other => println!("{:?}", other),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn place_order(&self, order: OrderRequest) -> Result<OrderStatus> {
+        self.place_orders(vec![order])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| HyperLiquidError::ExchangeError("Exchange returned no status for the order".to_string()))
+    }
+
+    /// Place several independent orders in one signed action
+    ///
+    /// Statuses are returned in the same order as the requests.
+    pub async fn place_orders(&self, orders: Vec<OrderRequest>) -> Result<Vec<OrderStatus>> {
+        self.place_orders_with_grouping(orders, Grouping::Na).await
+    }
+
+    /// Place several orders in one signed action with an explicit TP/SL grouping
+    pub async fn place_orders_with_grouping(
+        &self,
+        orders: Vec<OrderRequest>,
+        grouping: Grouping,
+    ) -> Result<Vec<OrderStatus>> {
+        let action = BulkOrder::new(&orders, grouping)?;
+        let response: ExchangeResponseData<OrderStatuses> = self.post_action(action).await?;
+
+        response
+            .data
+            .map(|data| data.statuses)
+            .ok_or_else(|| HyperLiquidError::ExchangeError("Exchange response is missing order statuses".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::signing::{action_hash, construct_phantom_agent, sign_l1_action, Wallet};
+    use crate::network::Network;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_decimal_to_wire() {
+        assert_eq!(decimal_to_wire(dec("1670.1")).unwrap(), "1670.1");
+        assert_eq!(decimal_to_wire(dec("1670.10000")).unwrap(), "1670.1");
+        assert_eq!(decimal_to_wire(dec("1000")).unwrap(), "1000");
+        assert_eq!(decimal_to_wire(dec("0.00000001")).unwrap(), "0.00000001");
+        assert_eq!(decimal_to_wire(dec("0.000")).unwrap(), "0");
+        assert!(decimal_to_wire(dec("0.000000001")).is_err());
+    }
+
+    #[test]
+    fn test_order_wire_format() {
+        let order = OrderRequest::trigger(1, false, dec("100"), dec("2.50"), dec("95.0"), true, Tpsl::Sl)
+            .with_reduce_only(true)
+            .with_cloid("0x00000000000000000000000000000001");
+
+        let wire = serde_json::to_value(order.to_wire().unwrap()).unwrap();
+        assert_eq!(
+            wire,
+            serde_json::json!({
+                "a": 1,
+                "b": false,
+                "p": "100",
+                "s": "2.5",
+                "r": true,
+                "t": {"trigger": {"isMarket": true, "triggerPx": "95", "tpsl": "sl"}},
+                "c": "0x00000000000000000000000000000001"
+            })
+        );
+
+        let limit = OrderRequest::limit(0, true, dec("1"), dec("1"), Tif::Alo);
+        let wire = serde_json::to_value(limit.to_wire().unwrap()).unwrap();
+        assert_eq!(wire["t"], serde_json::json!({"limit": {"tif": "Alo"}}));
+        assert!(wire.get("c").is_none());
+    }
+
+    #[test]
+    fn test_invalid_orders_rejected() {
+        assert!(OrderRequest::limit(0, true, dec("1"), dec("0"), Tif::Gtc).to_wire().is_err());
+        assert!(OrderRequest::limit(0, true, dec("-1"), dec("1"), Tif::Gtc).to_wire().is_err());
+        assert!(OrderRequest::limit(0, true, dec("1"), dec("1"), Tif::Gtc).with_cloid("0x1234").to_wire().is_err());
+        assert!(BulkOrder::new(&[], Grouping::Na).is_err());
+    }
+
+    #[test]
+    fn test_phantom_agent_creation_matches_production() {
+        let order = OrderRequest::limit(4, true, dec("1670.1"), dec("0.0147"), Tif::Ioc);
+        let action = BulkOrder::new(&[order], Grouping::Na).unwrap();
+
+        let hash = action_hash(&action, None, 1677777606040).unwrap();
+        let agent = construct_phantom_agent(hash, &Network::Mainnet);
+        assert_eq!(
+            format!("0x{}", hex::encode(agent.connection_id)),
+            "0x0fcbeda5ae3c4950a548021552a4fea2226858c4453571bf3f24ba017eac2908"
+        );
+    }
+
+    #[test]
+    fn test_l1_action_signing_order_matches() {
+        let wallet =
+            Wallet::from_private_key("0x0123456789012345678901234567890123456789012345678901234567890123").unwrap();
+        let order = OrderRequest::limit(1, true, dec("100"), dec("100"), Tif::Gtc);
+        let action = BulkOrder::new(&[order], Grouping::Na).unwrap();
+
+        let mainnet = sign_l1_action(&wallet, &action, None, 0, &Network::Mainnet).unwrap();
+        assert_eq!(mainnet.r, "0xd65369825a9df5d80099e513cce430311d7d26ddf477f5b3a33d2806b100d78e");
+        assert_eq!(mainnet.s, "0x2b54116ff64054968aa237c20ca9ff68000f977c93289157748a3162b6ea940e");
+        assert_eq!(mainnet.v, 28);
+
+        let testnet = sign_l1_action(&wallet, &action, None, 0, &Network::Testnet).unwrap();
+        assert_eq!(testnet.r, "0x82b2ba28e76b3d761093aaded1b1cdad4960b3af30212b343fb2e6cdfa4e3d54");
+        assert_eq!(testnet.s, "0x6b53878fc99d26047f4d7e8c90eb98955a109f44209163f52d8dc4278cbbd9f5");
+        assert_eq!(testnet.v, 27);
+    }
+
+    #[test]
+    fn test_order_status_parsing() {
+        let data: OrderStatuses = serde_json::from_value(serde_json::json!({
+            "statuses": [
+                {"resting": {"oid": 77738308}},
+                {"filled": {"totalSz": "0.02", "avgPx": "1891.4", "oid": 77747314}},
+                {"error": "Order must have minimum value of $10."},
+                "waitingForTrigger",
+                "waitingForQueue",
+                {"queued": {"oid": 1}}
+            ]
+        }))
+        .unwrap();
+
+        assert!(matches!(&data.statuses[0], OrderStatus::Resting(r) if r.oid == 77738308));
+        assert!(matches!(&data.statuses[1], OrderStatus::Filled(f) if f.avg_px == dec("1891.4") && f.total_sz == dec("0.02")));
+        assert!(matches!(&data.statuses[2], OrderStatus::Error(e) if e.contains("minimum value")));
+        assert!(matches!(&data.statuses[3], OrderStatus::WaitingForTrigger));
+        assert!(matches!(&data.statuses[4], OrderStatus::Unknown(status) if status == "waitingForQueue"));
+        assert!(matches!(&data.statuses[5], OrderStatus::Unknown(status) if status["queued"]["oid"] == 1));
+    }
+}
@@ -0,0 +1,266 @@
+//! EIP-712 signing for L1 actions sent to the `/exchange` endpoint.
+//!
+//! L1 actions are msgpack-encoded, hashed together with the nonce and optional vault
+//! address, and the resulting hash is signed as the `connectionId` of a "phantom agent"
+//! under the `Exchange` EIP-712 domain.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use k256::ecdsa::SigningKey;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use crate::address::Address;
+use crate::errors::{HyperLiquidError, Result};
+use crate::network::Network;
+
+/// Chain id of the `Exchange` EIP-712 domain used for L1 actions
+pub const L1_CHAIN_ID: u64 = 1337;
+
+/// A local secp256k1 key used to sign exchange actions
+///
+/// Clones share the same nonce counter, so a wallet can be used from several tasks
+/// without producing duplicate nonces.
+#[derive(Clone)]
+pub struct Wallet {
+    signing_key: SigningKey,
+    address: Address,
+    last_nonce: Arc<AtomicU64>,
+}
+
+impl Wallet {
+    /// Create a wallet from a hex-encoded private key, with or without a `0x` prefix
+    pub fn from_private_key(private_key: &str) -> Result<Self> {
+        let key_hex = private_key.strip_prefix("0x").unwrap_or(private_key);
+        let key_bytes = hex::decode(key_hex)
+            .map_err(|e| HyperLiquidError::SigningError(format!("Invalid private key hex: {}", e)))?;
+        let signing_key = SigningKey::from_slice(&key_bytes)
+            .map_err(|e| HyperLiquidError::SigningError(format!("Invalid private key: {}", e)))?;
+
+        let public_key = signing_key.verifying_key().to_encoded_point(false);
+        let address_hash = keccak256(&public_key.as_bytes()[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&address_hash[12..]);
+        let address = Address::from_bytes(address);
+
+        Ok(Self {
+            signing_key,
+            address,
+            last_nonce: Arc::new(AtomicU64::new(0)),
+        })
+    }
+
+    /// Address derived from the public key
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Next nonce for this wallet: the current time in milliseconds, bumped if needed
+    /// so that it is strictly greater than any nonce previously handed out
+    pub fn next_nonce(&self) -> u64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        let previous = self
+            .last_nonce
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| Some(now.max(last + 1)))
+            .unwrap_or_else(|last| last);
+        now.max(previous + 1)
+    }
+
+    /// Sign a 32-byte digest, returning an Ethereum-style `{r, s, v}` signature
+    pub fn sign_hash(&self, hash: &[u8; 32]) -> Result<Signature> {
+        let (signature, recovery_id) = self
+            .signing_key
+            .sign_prehash_recoverable(hash)
+            .map_err(|e| HyperLiquidError::SigningError(format!("Failed to sign hash: {}", e)))?;
+
+        Ok(Signature {
+            r: format!("0x{}", hex::encode(signature.r().to_bytes())),
+            s: format!("0x{}", hex::encode(signature.s().to_bytes())),
+            v: 27 + recovery_id.to_byte(),
+        })
+    }
+}
+
+impl std::fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the private key
+        f.debug_struct("Wallet").field("address", &self.address).finish()
+    }
+}
+
+/// Ethereum-style ECDSA signature as expected by the exchange endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Signature {
+    pub r: String,
+    pub s: String,
+    pub v: u8,
+}
+
+/// The EIP-712 `Agent` message that L1 actions are signed as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhantomAgent {
+    /// "a" on mainnet, "b" on testnet
+    pub source: String,
+    /// Hash of the msgpack-encoded action, nonce and vault address
+    pub connection_id: [u8; 32],
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Compute the hash of an L1 action
+///
+/// The hash covers the msgpack encoding of the action, the big-endian nonce and a
+/// vault address marker (`0x00` when trading for yourself, `0x01` followed by the
+/// vault address otherwise).
+pub fn action_hash<A: Serialize>(action: &A, vault_address: Option<Address>, nonce: u64) -> Result<[u8; 32]> {
+    let mut data = rmp_serde::to_vec_named(action)
+        .map_err(|e| HyperLiquidError::SigningError(format!("Failed to msgpack-encode action: {}", e)))?;
+    data.extend_from_slice(&nonce.to_be_bytes());
+
+    match vault_address {
+        Some(vault_address) => {
+            data.push(1);
+            data.extend_from_slice(vault_address.as_bytes());
+        }
+        None => data.push(0),
+    }
+
+    Ok(keccak256(&data))
+}
+
+pub fn construct_phantom_agent(hash: [u8; 32], network: &Network) -> PhantomAgent {
+    PhantomAgent {
+        source: network.phantom_agent_source().to_string(),
+        connection_id: hash,
+    }
+}
+
+/// EIP-712 digest of a phantom agent under the `Exchange` domain
+fn phantom_agent_digest(agent: &PhantomAgent) -> [u8; 32] {
+    let domain_type_hash =
+        keccak256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)");
+    let mut domain = Vec::with_capacity(32 * 5);
+    domain.extend_from_slice(&domain_type_hash);
+    domain.extend_from_slice(&keccak256(b"Exchange"));
+    domain.extend_from_slice(&keccak256(b"1"));
+    domain.extend_from_slice(&u256_bytes(L1_CHAIN_ID));
+    // verifyingContract is the zero address
+    domain.extend_from_slice(&[0u8; 32]);
+    let domain_separator = keccak256(&domain);
+
+    let agent_type_hash = keccak256(b"Agent(string source,bytes32 connectionId)");
+    let mut message = Vec::with_capacity(32 * 3);
+    message.extend_from_slice(&agent_type_hash);
+    message.extend_from_slice(&keccak256(agent.source.as_bytes()));
+    message.extend_from_slice(&agent.connection_id);
+    let struct_hash = keccak256(&message);
+
+    let mut digest = Vec::with_capacity(2 + 32 * 2);
+    digest.extend_from_slice(&[0x19, 0x01]);
+    digest.extend_from_slice(&domain_separator);
+    digest.extend_from_slice(&struct_hash);
+    keccak256(&digest)
+}
+
+fn u256_bytes(value: u64) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[24..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+/// Sign an L1 action with the given wallet
+pub fn sign_l1_action<A: Serialize>(
+    wallet: &Wallet,
+    action: &A,
+    vault_address: Option<Address>,
+    nonce: u64,
+    network: &Network,
+) -> Result<Signature> {
+    let hash = action_hash(action, vault_address, nonce)?;
+    let agent = construct_phantom_agent(hash, network);
+    wallet.sign_hash(&phantom_agent_digest(&agent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_PRIVATE_KEY: &str = "0x0123456789012345678901234567890123456789012345678901234567890123";
+
+    #[derive(Serialize)]
+    struct DummyAction {
+        #[serde(rename = "type")]
+        action_type: String,
+        num: u64,
+    }
+
+    fn dummy_action() -> DummyAction {
+        DummyAction {
+            action_type: "dummy".to_string(),
+            // 1000 scaled by 1e8, as done for float values in hashed actions
+            num: 100_000_000_000,
+        }
+    }
+
+    #[test]
+    fn test_wallet_address_derivation() {
+        let wallet = Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap();
+        assert_eq!(wallet.address(), "0x14791697260e4c9a71f18484c9f997b308e59325");
+        assert!(!format!("{:?}", wallet).contains("0123456789"));
+    }
+
+    #[test]
+    fn test_nonces_are_strictly_increasing() {
+        let wallet = Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap();
+        let clone = wallet.clone();
+        let mut last = 0;
+        for i in 0..100 {
+            let nonce = if i % 2 == 0 { wallet.next_nonce() } else { clone.next_nonce() };
+            assert!(nonce > last);
+            last = nonce;
+        }
+    }
+
+    #[test]
+    fn test_invalid_private_key() {
+        assert!(Wallet::from_private_key("0x1234").is_err());
+        assert!(Wallet::from_private_key("not hex").is_err());
+        assert!(Wallet::from_private_key(&format!("0x{}", "0".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn test_l1_action_signing_matches() {
+        let wallet = Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap();
+
+        let mainnet = sign_l1_action(&wallet, &dummy_action(), None, 0, &Network::Mainnet).unwrap();
+        assert_eq!(mainnet.r, "0x053749d5b30552aeb2fca34b530185976545bb22d0b3ce6f62e31be961a59298");
+        assert_eq!(mainnet.s, "0x755c40ba9bf05223521753995abb2f73ab3229be8ec921f350cb447e384d8ed8");
+        assert_eq!(mainnet.v, 27);
+
+        let testnet = sign_l1_action(&wallet, &dummy_action(), None, 0, &Network::Testnet).unwrap();
+        assert_eq!(testnet.r, "0x542af61ef1f429707e3c76c5293c80d01f74ef853e34b76efffcb57e574f9510");
+        assert_eq!(testnet.s, "0x17b8b32f086e8cdede991f1e2c529f5dd5297cbe8128500e00cbaf766204a613");
+        assert_eq!(testnet.v, 28);
+    }
+
+    #[test]
+    fn test_vault_address_changes_hash() {
+        let vault: Address = "0x1719884eb866cb12b2287399b15f7db5e7d775ea".parse().unwrap();
+        let without_vault = action_hash(&dummy_action(), None, 0).unwrap();
+        let with_vault = action_hash(&dummy_action(), Some(vault), 0).unwrap();
+        assert_ne!(without_vault, with_vault);
+    }
+
+    #[test]
+    fn test_phantom_agent_source() {
+        assert_eq!(construct_phantom_agent([0u8; 32], &Network::Mainnet).source, "a");
+        assert_eq!(construct_phantom_agent([0u8; 32], &Network::Testnet).source, "b");
+    }
+}
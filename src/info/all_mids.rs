@@ -9,6 +9,7 @@ use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::client::HyperLiquidClient;
+use crate::errors::Result;
 
 /// A price value that deserializes from JSON string to Decimal
 #[derive(Deserialize, Debug, Clone)]
@@ -36,11 +37,11 @@ impl HyperLiquidClient {
     /// 
     /// # Examples
     /// 
-    /// ```rust
+    /// ```rust,no_run
     /// use hyperliquid::client::HyperLiquidClient;
     /// 
     /// #[tokio::main]
-    /// async fn main() -> anyhow::Result<()> {
+    /// async fn main() -> hyperliquid::errors::Result<()> {
     ///     let client = HyperLiquidClient::new();
     ///     let mid_prices = client.get_all_mids().await?;
     ///     
@@ -54,11 +55,11 @@ impl HyperLiquidClient {
     /// 
     /// # Errors
     /// 
-    /// Returns an error if:
-    /// - Network request fails
-    /// - API returns an error response
-    /// - Response cannot be parsed
-    pub async fn get_all_mids(&self) -> anyhow::Result<MidPrices> {
+    /// Returns a [`HyperLiquidError`](crate::errors::HyperLiquidError) if:
+    /// - Network request fails (`ConnectionError`, `Timeout`)
+    /// - API returns an error response (`ApiError`, `RateLimitExceeded`, `ServerError`)
+    /// - Response cannot be parsed (`DecodeError`)
+    pub async fn get_all_mids(&self) -> Result<MidPrices> {
         self.make_basic_request("allMids").await
     }
 }
@@ -2,22 +2,36 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::client::HyperLiquidClient;
-use crate::errors::{validate_coin_symbol, validate_time_range, validate_interval};
+use crate::errors::{validate_coin_symbol, validate_time_range, validate_interval, Result};
 
-#[derive(Serialize)]
-struct CandleSnapshotRequest {
+#[derive(Debug, Clone, Serialize)]
+pub struct CandleSnapshotRequest {
     #[serde(rename = "type")]
-    request_type: String,
-    req: CandleRequest,
+    pub request_type: String,
+    pub req: CandleRequest,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct CandleRequest {
-    coin: String,
-    interval: String,
-    start_time: u64,
-    end_time: u64,
+pub struct CandleRequest {
+    pub coin: String,
+    pub interval: String,
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+impl CandleSnapshotRequest {
+    pub fn new(coin: impl Into<String>, interval: impl Into<String>, start_time: u64, end_time: u64) -> Self {
+        Self {
+            request_type: "candleSnapshot".to_string(),
+            req: CandleRequest {
+                coin: coin.into(),
+                interval: interval.into(),
+                start_time,
+                end_time,
+            },
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -51,21 +65,13 @@ pub struct CandleData {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_candle_snapshot(&self, coin: &str, interval: &str, start_time: u64, end_time: u64) -> anyhow::Result<Vec<CandleData>> {
+    pub async fn get_candle_snapshot(&self, coin: &str, interval: &str, start_time: u64, end_time: u64) -> Result<Vec<CandleData>> {
         // Validate inputs
         validate_coin_symbol(coin)?;
         validate_interval(interval)?;
         validate_time_range(start_time, end_time)?;
         
-        let request_body = CandleSnapshotRequest {
-            request_type: "candleSnapshot".to_string(),
-            req: CandleRequest {
-                coin: coin.to_string(),
-                interval: interval.to_string(),
-                start_time,
-                end_time,
-            },
-        };
+        let request_body = CandleSnapshotRequest::new(coin, interval, start_time, end_time);
 
         self.make_custom_request(&request_body).await
     }
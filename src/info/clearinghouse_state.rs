@@ -0,0 +1,186 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::address::{Address, IntoAddress};
+use crate::client::HyperLiquidClient;
+use crate::errors::Result;
+
+#[derive(Serialize)]
+struct ClearinghouseStateRequest {
+    #[serde(rename = "type")]
+    request_type: String,
+    user: Address,
+    /// Builder-deployed perp dex; the first perp dex when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dex: Option<String>,
+}
+
+/// Perpetuals account summary and open positions
+#[derive(Debug, Deserialize)]
+pub struct ClearinghouseState {
+    #[serde(rename = "marginSummary")]
+    pub margin_summary: MarginSummary,
+    #[serde(rename = "crossMarginSummary")]
+    pub cross_margin_summary: MarginSummary,
+    #[serde(rename = "crossMaintenanceMarginUsed")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub cross_maintenance_margin_used: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub withdrawable: Decimal,
+    #[serde(rename = "assetPositions")]
+    pub asset_positions: Vec<AssetPosition>,
+    pub time: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MarginSummary {
+    #[serde(rename = "accountValue")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub account_value: Decimal,
+    #[serde(rename = "totalNtlPos")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub total_ntl_pos: Decimal,
+    #[serde(rename = "totalRawUsd")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub total_raw_usd: Decimal,
+    #[serde(rename = "totalMarginUsed")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub total_margin_used: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssetPosition {
+    /// Position mode, e.g. `oneWay`
+    #[serde(rename = "type")]
+    pub position_type: String,
+    pub position: Position,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub coin: String,
+    /// Signed size: positive for longs, negative for shorts
+    #[serde(with = "rust_decimal::serde::str")]
+    pub szi: Decimal,
+    pub leverage: Leverage,
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    pub entry_px: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub position_value: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub unrealized_pnl: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub return_on_equity: Decimal,
+    /// Absent when the position cannot be liquidated
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    pub liquidation_px: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub margin_used: Decimal,
+    pub max_leverage: u32,
+    pub cum_funding: CumFunding,
+}
+
+/// Leverage setting of a position
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Leverage {
+    Cross {
+        value: u32,
+    },
+    #[serde(rename_all = "camelCase")]
+    Isolated {
+        value: u32,
+        /// USD allocated to the isolated position, net of the position's notional
+        #[serde(with = "rust_decimal::serde::str")]
+        raw_usd: Decimal,
+    },
+}
+
+impl Leverage {
+    pub fn value(&self) -> u32 {
+        match self {
+            Leverage::Cross { value } | Leverage::Isolated { value, .. } => *value,
+        }
+    }
+}
+
+/// Funding paid by a position; positive values were paid, negative values received
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CumFunding {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub all_time: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub since_open: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub since_change: Decimal,
+}
+
+/// Spot token balances
+#[derive(Debug, Deserialize)]
+pub struct SpotState {
+    pub balances: Vec<Balance>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Balance {
+    pub coin: String,
+    /// Spot token index, resolved with [`crate::info::spot_meta::SpotMeta::token`]
+    pub token: u32,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub total: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub hold: Decimal,
+    #[serde(rename = "entryNtl")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub entry_ntl: Decimal,
+}
+
+impl HyperLiquidClient {
+    /// Get a user's perpetuals margin summary and open positions
+    ///
+    /// `dex` selects a builder-deployed perp dex; `None` queries the default one.
+    pub async fn get_clearinghouse_state(&self, user: impl IntoAddress, dex: Option<&str>) -> Result<ClearinghouseState> {
+        let user = user.into_address()?;
+
+        let request_body = ClearinghouseStateRequest {
+            request_type: "clearinghouseState".to_string(),
+            user,
+            dex: dex.map(str::to_string),
+        };
+
+        self.make_custom_request(&request_body).await
+    }
+
+    /// Get a user's spot token balances
+    pub async fn get_spot_clearinghouse_state(&self, user: impl IntoAddress) -> Result<SpotState> {
+        self.make_user_request("spotClearinghouseState", user).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_deserialization() {
+        let position: AssetPosition = serde_json::from_value(serde_json::json!({
+            "type": "oneWay",
+            "position": {
+                "coin": "ETH", "szi": "-0.0335",
+                "leverage": {"type": "isolated", "value": 10, "rawUsd": "110.03"},
+                "entryPx": "2986.3", "positionValue": "100.02765", "unrealizedPnl": "-0.0134",
+                "returnOnEquity": "-0.0026789", "liquidationPx": null, "marginUsed": "10.0",
+                "maxLeverage": 50,
+                "cumFunding": {"allTime": "514.085417", "sinceOpen": "0.0", "sinceChange": "0.0"}
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(position.position.szi, Decimal::new(-335, 4));
+        assert_eq!(position.position.leverage.value(), 10);
+        assert!(matches!(position.position.leverage, Leverage::Isolated { raw_usd, .. } if raw_usd == Decimal::new(11003, 2)));
+        assert!(position.position.liquidation_px.is_none());
+    }
+}
@@ -1,23 +1,23 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::{Address, IntoAddress};
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 pub type DelegationsResponse = Vec<ValidatorStaking>;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ValidatorStaking {
-    pub validator: String,
+    pub validator: Address,
     #[serde(with = "rust_decimal::serde::str")]
     pub amount: Decimal,
     pub locked_until_timestamp: u64,
 }
 
 impl HyperLiquidClient {
-    pub async fn get_delegations(&self, user: &str) -> anyhow::Result<DelegationsResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_delegations(&self, user: impl IntoAddress) -> Result<DelegationsResponse> {
         self.make_user_request("delegations", user).await
     }
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::{Address, IntoAddress};
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 pub type DelegatorHistoryResponse = Vec<DelegatorHistory>;
 
@@ -21,15 +22,14 @@ pub struct DelegatorHistoryDelta {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DelegatorHistoryDelegate {
-    pub validator: String,
+    pub validator: Address,
     #[serde(with = "rust_decimal::serde::str")]
     pub amount: Decimal,
     pub is_undelegate: bool,
 }
 
 impl HyperLiquidClient {
-    pub async fn get_delegator_history(&self, user: &str) -> anyhow::Result<DelegatorHistoryResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_delegator_history(&self, user: impl IntoAddress) -> Result<DelegatorHistoryResponse> {
         self.make_user_request("delegatorHistory", user).await
     }
 }
\ No newline at end of file
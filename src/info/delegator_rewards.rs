@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 pub type DelegatorRewardsResponse = Vec<DelegatorRewards>;
 
@@ -16,8 +17,7 @@ pub struct DelegatorRewards {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_delegator_rewards(&self, user: &str) -> anyhow::Result<DelegatorRewardsResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_delegator_rewards(&self, user: impl IntoAddress) -> Result<DelegatorRewardsResponse> {
         self.make_user_request("delegatorRewards", user).await
     }
 }   
\ No newline at end of file
@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -17,8 +18,7 @@ pub struct DelegatorSummaryResponse {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_delegator_summary(&self, user: &str) -> anyhow::Result<DelegatorSummaryResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_delegator_summary(&self, user: impl IntoAddress) -> Result<DelegatorSummaryResponse> {
         self.make_user_request("delegatorSummary", user).await
     }
 }
@@ -1,12 +1,12 @@
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 use crate::types::Order;
 
 pub type FrontendOpenOrdersResponse = Order;
 
 impl HyperLiquidClient {
-    pub async fn get_frontend_open_orders(&self, user: &str) -> anyhow::Result<Vec<FrontendOpenOrdersResponse>> {
-        validate_ethereum_address(user)?;
+    pub async fn get_frontend_open_orders(&self, user: impl IntoAddress) -> Result<Vec<FrontendOpenOrdersResponse>> {
         self.make_user_request("frontendOpenOrders", user).await
     }
 }
\ No newline at end of file
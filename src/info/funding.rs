@@ -0,0 +1,144 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::address::{Address, IntoAddress};
+use crate::client::HyperLiquidClient;
+use crate::errors::{validate_coin_symbol, validate_time_range, Result};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserFundingRequest {
+    #[serde(rename = "type")]
+    request_type: String,
+    user: Address,
+    start_time: u64,
+    end_time: Option<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FundingHistoryRequest {
+    #[serde(rename = "type")]
+    request_type: String,
+    coin: String,
+    start_time: u64,
+    end_time: Option<u64>,
+}
+
+/// A funding payment of one of the user's positions
+#[derive(Debug, Deserialize)]
+pub struct UserFunding {
+    pub time: u64,
+    pub hash: String,
+    pub delta: FundingDelta,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingDelta {
+    pub coin: String,
+    /// USDC paid (negative) or received (positive)
+    #[serde(with = "rust_decimal::serde::str")]
+    pub usdc: Decimal,
+    /// Signed position size at the time of the payment
+    #[serde(with = "rust_decimal::serde::str")]
+    pub szi: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub funding_rate: Decimal,
+    #[serde(default)]
+    pub n_samples: Option<u32>,
+}
+
+/// A historical funding rate of a coin
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingHistory {
+    pub coin: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub funding_rate: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub premium: Decimal,
+    pub time: u64,
+}
+
+/// Predicted funding of a coin on one venue
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PredictedFunding {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub funding_rate: Decimal,
+    pub next_funding_time: u64,
+    #[serde(default)]
+    pub funding_interval_hours: Option<u32>,
+}
+
+/// Predicted fundings per venue (`HlPerp`, `BinPerp`, `BybitPerp`) of one coin; a venue
+/// that does not list the coin has no prediction
+pub type VenueFundings = Vec<(String, Option<PredictedFunding>)>;
+
+/// Predicted fundings of every coin, as `(coin, venues)` pairs
+pub type PredictedFundingsResponse = Vec<(String, VenueFundings)>;
+
+impl HyperLiquidClient {
+    /// Get a user's funding payments between `start_time` and `end_time` (now if `None`)
+    pub async fn get_user_funding(&self, user: impl IntoAddress, start_time: u64, end_time: Option<u64>) -> Result<Vec<UserFunding>> {
+        let user = user.into_address()?;
+        if let Some(end_time) = end_time {
+            validate_time_range(start_time, end_time)?;
+        }
+
+        let request_body = UserFundingRequest {
+            request_type: "userFunding".to_string(),
+            user,
+            start_time,
+            end_time,
+        };
+
+        self.make_custom_request(&request_body).await
+    }
+
+    /// Get the funding rates of a coin between `start_time` and `end_time` (now if `None`)
+    pub async fn get_funding_history(&self, coin: &str, start_time: u64, end_time: Option<u64>) -> Result<Vec<FundingHistory>> {
+        validate_coin_symbol(coin)?;
+        if let Some(end_time) = end_time {
+            validate_time_range(start_time, end_time)?;
+        }
+
+        let request_body = FundingHistoryRequest {
+            request_type: "fundingHistory".to_string(),
+            coin: coin.to_string(),
+            start_time,
+            end_time,
+        };
+
+        self.make_custom_request(&request_body).await
+    }
+
+    /// Get the next predicted funding rate of every coin on Hyperliquid and other venues
+    pub async fn get_predicted_fundings(&self) -> Result<PredictedFundingsResponse> {
+        self.make_basic_request("predictedFundings").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predicted_fundings_deserialization() {
+        let fundings: PredictedFundingsResponse = serde_json::from_value(serde_json::json!([
+            ["AVAX", [
+                ["BinPerp", {"fundingRate": "0.0001", "nextFundingTime": 1733961600000u64}],
+                ["HlPerp", {"fundingRate": "0.0000125", "nextFundingTime": 1733958000000u64, "fundingIntervalHours": 1}],
+                ["BybitPerp", null]
+            ]]
+        ]))
+        .unwrap();
+
+        let (coin, venues) = &fundings[0];
+        assert_eq!(coin, "AVAX");
+        assert_eq!(venues[1].0, "HlPerp");
+        assert_eq!(venues[1].1.as_ref().unwrap().funding_interval_hours, Some(1));
+        assert!(venues[2].1.is_none());
+    }
+}
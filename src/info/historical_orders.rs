@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use crate::{client::HyperLiquidClient, errors::validate_ethereum_address, types::{Order, OrderState}};
+use crate::{address::IntoAddress, client::HyperLiquidClient, errors::Result, types::{Order, OrderState}};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -11,8 +11,7 @@ pub struct HistoricalOrdersResponse {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_historical_orders(&self, user: &str) -> anyhow::Result<Vec<HistoricalOrdersResponse>> {
-        validate_ethereum_address(user)?;
+    pub async fn get_historical_orders(&self, user: impl IntoAddress) -> Result<Vec<HistoricalOrdersResponse>> {
         self.make_user_request("historicalOrders", user).await
     }
 }
\ No newline at end of file
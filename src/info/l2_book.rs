@@ -2,16 +2,27 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_coin_symbol;
+use crate::errors::{validate_coin_symbol, Result};
 
-#[derive(Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct L2BookRequest {
+pub struct L2BookRequest {
     #[serde(rename = "type")]
-    request_type: String,
-    coin: String,
-    n_sig_figs: Option<u64>,
-    mantissa: Option<u64>,
+    pub request_type: String,
+    pub coin: String,
+    pub n_sig_figs: Option<u64>,
+    pub mantissa: Option<u64>,
+}
+
+impl L2BookRequest {
+    pub fn new(coin: impl Into<String>) -> Self {
+        Self {
+            request_type: "l2Book".to_string(),
+            coin: coin.into(),
+            n_sig_figs: None,
+            mantissa: None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -34,15 +45,10 @@ pub struct Level {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_l2_book(&self, coin: &str) -> anyhow::Result<L2BookResponse> {
+    pub async fn get_l2_book(&self, coin: &str) -> Result<L2BookResponse> {
         validate_coin_symbol(coin)?;
         
-        let request_body = L2BookRequest {
-            request_type: "l2Book".to_string(),
-            coin: coin.to_string(),
-            n_sig_figs: None,
-            mantissa: None,
-        };
+        let request_body = L2BookRequest::new(coin);
 
         self.make_custom_request(&request_body).await
     }
@@ -0,0 +1,243 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::address::{Address, IntoAddress};
+use crate::client::HyperLiquidClient;
+use crate::errors::{validate_time_range, Result};
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserNonFundingLedgerUpdatesRequest {
+    #[serde(rename = "type")]
+    request_type: String,
+    user: Address,
+    start_time: u64,
+    end_time: Option<u64>,
+}
+
+/// A change to a user's balances other than funding
+#[derive(Debug, Clone, Deserialize)]
+pub struct LedgerUpdate {
+    pub time: u64,
+    pub hash: String,
+    pub delta: LedgerDelta,
+}
+
+/// What a [`LedgerUpdate`] did, tagged by its `type`
+///
+/// Amounts are in USDC unless a `token` is given. Delta types this crate does not model,
+/// or whose payload does not match, are kept as [`LedgerDelta::Unknown`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum LedgerDelta {
+    Deposit {
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+    },
+    #[serde(rename_all = "camelCase")]
+    Withdraw {
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        fee: Decimal,
+        #[serde(default)]
+        nonce: Option<u64>,
+    },
+    /// USDC transfer between perp accounts
+    #[serde(rename_all = "camelCase")]
+    InternalTransfer {
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+        user: Address,
+        destination: Address,
+        #[serde(with = "rust_decimal::serde::str")]
+        fee: Decimal,
+    },
+    #[serde(rename_all = "camelCase")]
+    SubAccountTransfer {
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+        user: Address,
+        destination: Address,
+    },
+    /// Transfer between the user's spot and perp balances
+    #[serde(rename_all = "camelCase")]
+    AccountClassTransfer {
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+        to_perp: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    SpotTransfer {
+        token: String,
+        #[serde(with = "rust_decimal::serde::str")]
+        amount: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc_value: Decimal,
+        user: Address,
+        destination: Address,
+        #[serde(with = "rust_decimal::serde::str")]
+        fee: Decimal,
+        #[serde(default, with = "rust_decimal::serde::str_option")]
+        native_token_fee: Option<Decimal>,
+        #[serde(default)]
+        nonce: Option<u64>,
+    },
+    SpotGenesis {
+        token: String,
+        #[serde(with = "rust_decimal::serde::str")]
+        amount: Decimal,
+    },
+    /// Transfer of any token between accounts and dexes
+    #[serde(rename_all = "camelCase")]
+    Send {
+        user: Address,
+        destination: Address,
+        #[serde(default)]
+        source_dex: Option<String>,
+        #[serde(default)]
+        destination_dex: Option<String>,
+        token: String,
+        #[serde(with = "rust_decimal::serde::str")]
+        amount: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc_value: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        fee: Decimal,
+        #[serde(default, with = "rust_decimal::serde::str_option")]
+        native_token_fee: Option<Decimal>,
+        #[serde(default)]
+        nonce: Option<u64>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Liquidation {
+        #[serde(with = "rust_decimal::serde::str")]
+        liquidated_ntl_pos: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        account_value: Decimal,
+        /// `Cross` or `Isolated`
+        leverage_type: String,
+        liquidated_positions: Vec<LiquidatedPosition>,
+    },
+    VaultCreate {
+        vault: Address,
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+        #[serde(default, with = "rust_decimal::serde::str_option")]
+        fee: Option<Decimal>,
+    },
+    VaultDeposit {
+        vault: Address,
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+    },
+    #[serde(rename_all = "camelCase")]
+    VaultWithdraw {
+        vault: Address,
+        user: Address,
+        #[serde(with = "rust_decimal::serde::str")]
+        requested_usd: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        commission: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        closing_cost: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        basis: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        net_withdrawn_usd: Decimal,
+    },
+    VaultDistribution {
+        vault: Address,
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+    },
+    VaultLeaderCommission {
+        user: Address,
+        #[serde(with = "rust_decimal::serde::str")]
+        usdc: Decimal,
+    },
+    RewardsClaim {
+        #[serde(with = "rust_decimal::serde::str")]
+        amount: Decimal,
+    },
+    /// Transfer between the spot balance and staking
+    #[serde(rename = "cStakingTransfer", rename_all = "camelCase")]
+    CStakingTransfer {
+        token: String,
+        #[serde(with = "rust_decimal::serde::str")]
+        amount: Decimal,
+        is_deposit: bool,
+    },
+    /// A delta type this crate does not model yet
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LiquidatedPosition {
+    pub coin: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub szi: Decimal,
+}
+
+impl HyperLiquidClient {
+    /// Get a user's deposits, withdrawals, transfers, vault flows and liquidations between
+    /// `start_time` and `end_time` (now if `None`)
+    pub async fn get_user_non_funding_ledger_updates(&self, user: impl IntoAddress, start_time: u64, end_time: Option<u64>) -> Result<Vec<LedgerUpdate>> {
+        let user = user.into_address()?;
+        if let Some(end_time) = end_time {
+            validate_time_range(start_time, end_time)?;
+        }
+
+        let request_body = UserNonFundingLedgerUpdatesRequest {
+            request_type: "userNonFundingLedgerUpdates".to_string(),
+            user,
+            start_time,
+            end_time,
+        };
+
+        self.make_custom_request(&request_body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delta(value: serde_json::Value) -> LedgerDelta {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_known_deltas() {
+        assert!(matches!(
+            delta(serde_json::json!({"type": "deposit", "usdc": "100.5"})),
+            LedgerDelta::Deposit { usdc } if usdc == Decimal::new(1005, 1)
+        ));
+        assert!(matches!(
+            delta(serde_json::json!({"type": "accountClassTransfer", "usdc": "10.0", "toPerp": false})),
+            LedgerDelta::AccountClassTransfer { to_perp: false, .. }
+        ));
+        assert!(matches!(
+            delta(serde_json::json!({"type": "cStakingTransfer", "token": "HYPE", "amount": "1.5", "isDeposit": true})),
+            LedgerDelta::CStakingTransfer { is_deposit: true, .. }
+        ));
+        assert!(matches!(
+            delta(serde_json::json!({
+                "type": "liquidation", "liquidatedNtlPos": "1000.0", "accountValue": "50.0",
+                "leverageType": "Cross", "liquidatedPositions": [{"coin": "ETH", "szi": "-0.5"}]
+            })),
+            LedgerDelta::Liquidation { liquidated_positions, .. } if liquidated_positions[0].coin == "ETH"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_delta_fallback() {
+        let value = serde_json::json!({"type": "borrowLend", "token": "USDC", "amount": "1"});
+        assert!(matches!(delta(value.clone()), LedgerDelta::Unknown(raw) if raw == value));
+
+        // A known type with an unexpected payload is kept rather than failing the response
+        let value = serde_json::json!({"type": "deposit", "amount": "1"});
+        assert!(matches!(delta(value), LedgerDelta::Unknown(_)));
+    }
+}
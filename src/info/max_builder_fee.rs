@@ -1,15 +1,16 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::address::{Address, IntoAddress};
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 #[derive(Serialize)]
 struct MaxBuilderFeeRequest {
     #[serde(rename = "type")]
     request_type: String,
-    user: String,
-    builder: String,
+    user: Address,
+    builder: Address,
 }
 
 #[derive(Debug, Deserialize)]
@@ -19,14 +20,14 @@ pub struct MaxBuilderFeeResponse {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_max_builder_fee(&self, user: &str, builder: &str) -> anyhow::Result<MaxBuilderFeeResponse> {
-        validate_ethereum_address(user)?;
-        validate_ethereum_address(builder)?;
+    pub async fn get_max_builder_fee(&self, user: impl IntoAddress, builder: impl IntoAddress) -> Result<MaxBuilderFeeResponse> {
+        let user = user.into_address()?;
+        let builder = builder.into_address()?;
         
         let request_body = MaxBuilderFeeRequest {
             request_type: "maxBuilderFee".to_string(),
-            user: user.to_string(),
-            builder: builder.to_string(),
+            user,
+            builder,
         };
 
         self.make_custom_request(&request_body).await
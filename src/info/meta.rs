@@ -0,0 +1,222 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::client::HyperLiquidClient;
+use crate::errors::Result;
+
+/// Offset added to the asset ids of builder-deployed perp dexs
+pub const PERP_DEX_ASSET_OFFSET: u32 = 100_000;
+
+/// Range of asset ids reserved for each builder-deployed perp dex
+pub const PERP_DEX_ASSET_STRIDE: u32 = 10_000;
+
+#[derive(Serialize)]
+struct MetaRequest {
+    #[serde(rename = "type")]
+    request_type: String,
+    /// Builder-deployed perp dex; the first perp dex when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dex: Option<String>,
+}
+
+/// Perpetuals universe and margin tables
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Meta {
+    /// Perpetual assets; on the default dex an asset's index in this list is its asset id for
+    /// exchange actions
+    pub universe: Vec<AssetInfo>,
+    /// Margin tables referenced by [`AssetInfo::margin_table_id`], as `(id, table)` pairs
+    #[serde(default)]
+    pub margin_tables: Vec<(u32, MarginTable)>,
+    /// Spot token index of the collateral asset
+    #[serde(default)]
+    pub collateral_token: Option<u32>,
+}
+
+impl Meta {
+    /// Asset id of a coin on the default perp dex, as used in
+    /// [`crate::exchange::order::OrderRequest::asset`]
+    ///
+    /// Use [`Meta::dex_asset_id`] for a meta fetched with a builder-deployed `dex`.
+    pub fn asset_id(&self, coin: &str) -> Option<u32> {
+        self.universe.iter().position(|asset| asset.name == coin).map(|index| index as u32)
+    }
+
+    /// Asset id of a coin on the perp dex at `dex_index` in the `perpDexs` list
+    ///
+    /// Assets of builder-deployed dexs are numbered
+    /// `100000 + dex_index * 10000 + index`; index 0 is the default dex. Returns `None` if
+    /// the coin is not listed or the id does not fit in a `u32`.
+    pub fn dex_asset_id(&self, coin: &str, dex_index: u32) -> Option<u32> {
+        let index = self.asset_id(coin)?;
+        if dex_index == 0 {
+            return Some(index);
+        }
+        dex_index
+            .checked_mul(PERP_DEX_ASSET_STRIDE)?
+            .checked_add(PERP_DEX_ASSET_OFFSET)?
+            .checked_add(index)
+    }
+
+    /// Margin table referenced by an asset
+    pub fn margin_table(&self, id: u32) -> Option<&MarginTable> {
+        self.margin_tables
+            .iter()
+            .find(|(table_id, _)| *table_id == id)
+            .map(|(_, table)| table)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetInfo {
+    pub name: String,
+    /// Number of decimals allowed in order sizes
+    pub sz_decimals: u32,
+    pub max_leverage: u32,
+    #[serde(default)]
+    pub margin_table_id: Option<u32>,
+    /// Only isolated margin is allowed for this asset
+    #[serde(default)]
+    pub only_isolated: bool,
+    #[serde(default)]
+    pub is_delisted: bool,
+    /// e.g. `strictIsolated` or `noCross`
+    #[serde(default)]
+    pub margin_mode: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTable {
+    pub description: String,
+    pub margin_tiers: Vec<MarginTier>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTier {
+    /// Position notional from which this tier applies
+    #[serde(with = "rust_decimal::serde::str")]
+    pub lower_bound: Decimal,
+    pub max_leverage: u32,
+}
+
+/// Live context of a perpetual asset
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetCtx {
+    /// Current hourly funding rate
+    #[serde(with = "rust_decimal::serde::str")]
+    pub funding: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub open_interest: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub prev_day_px: Decimal,
+    /// 24h notional volume
+    #[serde(with = "rust_decimal::serde::str")]
+    pub day_ntl_vlm: Decimal,
+    /// 24h volume in units of the asset
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    pub day_base_vlm: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    pub premium: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub oracle_px: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub mark_px: Decimal,
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    pub mid_px: Option<Decimal>,
+    /// Impact bid and ask prices
+    #[serde(default)]
+    pub impact_pxs: Option<Vec<Decimal>>,
+}
+
+/// [`Meta`] together with one [`AssetCtx`] per asset, in universe order
+pub type MetaAndAssetCtxsResponse = (Meta, Vec<AssetCtx>);
+
+impl HyperLiquidClient {
+    /// Get the perpetuals universe and margin tables
+    ///
+    /// `dex` selects a builder-deployed perp dex; `None` queries the default one.
+    pub async fn get_meta(&self, dex: Option<&str>) -> Result<Meta> {
+        let request_body = MetaRequest {
+            request_type: "meta".to_string(),
+            dex: dex.map(str::to_string),
+        };
+
+        self.make_custom_request(&request_body).await
+    }
+
+    /// Get the perpetuals universe with funding, open interest and prices of every asset
+    pub async fn get_meta_and_asset_ctxs(&self, dex: Option<&str>) -> Result<MetaAndAssetCtxsResponse> {
+        let request_body = MetaRequest {
+            request_type: "metaAndAssetCtxs".to_string(),
+            dex: dex.map(str::to_string),
+        };
+
+        self.make_custom_request(&request_body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meta_deserialization() {
+        let meta: Meta = serde_json::from_value(serde_json::json!({
+            "universe": [
+                {"szDecimals": 5, "name": "BTC", "maxLeverage": 40, "marginTableId": 56},
+                {"szDecimals": 1, "name": "MATIC", "maxLeverage": 20, "marginTableId": 20, "isDelisted": true},
+                {"szDecimals": 0, "name": "XYZ", "maxLeverage": 3, "onlyIsolated": true, "marginMode": "strictIsolated"}
+            ],
+            "marginTables": [[56, {"description": "tiered 40x", "marginTiers": [
+                {"lowerBound": "0.0", "maxLeverage": 40},
+                {"lowerBound": "150000000.0", "maxLeverage": 20}
+            ]}]],
+            "collateralToken": 0
+        }))
+        .unwrap();
+
+        assert_eq!(meta.asset_id("MATIC"), Some(1));
+        assert_eq!(meta.dex_asset_id("MATIC", 0), Some(1));
+        assert_eq!(meta.dex_asset_id("XYZ", 1), Some(110_002));
+        assert_eq!(meta.dex_asset_id("ETH", 1), None);
+        assert_eq!(meta.dex_asset_id("XYZ", 429_486), Some(4_294_960_002));
+        assert_eq!(meta.dex_asset_id("XYZ", 429_487), None);
+        assert_eq!(meta.dex_asset_id("XYZ", u32::MAX), None);
+        assert!(meta.universe[1].is_delisted);
+        assert!(meta.universe[2].only_isolated);
+        assert_eq!(meta.margin_table(56).unwrap().margin_tiers[1].max_leverage, 20);
+        assert!(meta.margin_table(20).is_none());
+    }
+
+    #[test]
+    fn test_asset_ctx_nullable_fields() {
+        let ctx: AssetCtx = serde_json::from_value(serde_json::json!({
+            "funding": "0.0000125", "openInterest": "100.5", "prevDayPx": "10.0", "dayNtlVlm": "0.0",
+            "premium": null, "oraclePx": "10.1", "markPx": "10.2", "midPx": null, "impactPxs": null,
+            "dayBaseVlm": "0.0"
+        }))
+        .unwrap();
+
+        assert_eq!(ctx.funding, Decimal::new(125, 7));
+        assert!(ctx.premium.is_none());
+        assert!(ctx.mid_px.is_none());
+        assert!(ctx.impact_pxs.is_none());
+    }
+
+    #[test]
+    fn test_asset_ctx_impact_prices() {
+        let ctx: AssetCtx = serde_json::from_value(serde_json::json!({
+            "funding": "0.0000125", "openInterest": "100.5", "prevDayPx": "10.0", "dayNtlVlm": "0.0",
+            "premium": "0.0001", "oraclePx": "10.1", "markPx": "10.2", "midPx": "10.15",
+            "impactPxs": ["10.14", "10.16"], "dayBaseVlm": "0.0"
+        }))
+        .unwrap();
+
+        assert_eq!(ctx.impact_pxs, Some(vec![Decimal::new(1014, 2), Decimal::new(1016, 2)]));
+    }
+}
@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 /// Simplified order structure returned by the openOrders endpoint
 #[derive(Debug, Deserialize)]
@@ -24,8 +25,7 @@ pub struct OpenOrdersResponse {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_open_orders(&self, user: &str) -> anyhow::Result<Vec<OpenOrdersResponse>> {
-        validate_ethereum_address(user)?;
+    pub async fn get_open_orders(&self, user: impl IntoAddress) -> Result<Vec<OpenOrdersResponse>> {
         self.make_user_request("openOrders", user).await
     }
 }
\ No newline at end of file
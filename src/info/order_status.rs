@@ -1,12 +1,12 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{client::HyperLiquidClient, errors::validate_ethereum_address, types::Order};
+use crate::{address::{Address, IntoAddress}, client::HyperLiquidClient, errors::Result, types::Order};
 
 #[derive(Serialize)]
 struct OrderStatusRequest {
     #[serde(rename = "type")]
     request_type: String,
-    user: String,
+    user: Address,
     oid: u64,
 }
 
@@ -27,13 +27,13 @@ pub struct OrderWithStatus {
 // Order struct is now imported from crate::types
 
 impl HyperLiquidClient {
-    pub async fn get_order_status(&self, user: &str, oid: u64) -> anyhow::Result<OrderStatusResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_order_status(&self, user: impl IntoAddress, oid: u64) -> Result<OrderStatusResponse> {
+        let user = user.into_address()?;
         
         let request_body = OrderStatusRequest {
             request_type: "orderStatus".to_string(),
-            user: user.to_string(),
-            oid: oid,
+            user,
+            oid,
         };
     
         self.make_custom_request(&request_body).await
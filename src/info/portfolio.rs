@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 pub type PortfolioResponse = Vec<PortfolioHistoryEntry>;
 
@@ -19,8 +20,7 @@ pub struct PortfolioHistoryData {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_portfolio(&self, user: &str) -> anyhow::Result<PortfolioResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_portfolio(&self, user: impl IntoAddress) -> Result<PortfolioResponse> {
         self.make_user_request("portfolio", user).await
     }
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,8 +36,7 @@ pub struct RewardHistoryItem {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_referral_info(&self, user: &str) -> anyhow::Result<ReferralResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_referral_info(&self, user: impl IntoAddress) -> Result<ReferralResponse> {
         self.make_user_request("referral", user).await
     }
 }
\ No newline at end of file
@@ -0,0 +1,147 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::address::Address;
+use crate::client::HyperLiquidClient;
+use crate::errors::Result;
+
+/// Offset added to a spot pair's index to form its asset id for exchange actions
+pub const SPOT_ASSET_OFFSET: u32 = 10_000;
+
+/// Spot tokens and trading pairs
+#[derive(Debug, Deserialize)]
+pub struct SpotMeta {
+    pub tokens: Vec<SpotToken>,
+    pub universe: Vec<SpotPair>,
+}
+
+impl SpotMeta {
+    /// Token with the given index, e.g. [`crate::info::clearinghouse_state::Balance::token`]
+    pub fn token(&self, index: u32) -> Option<&SpotToken> {
+        self.tokens.iter().find(|token| token.index == index)
+    }
+
+    /// Pair by its coin name, either `PURR/USDC` or `@N`
+    pub fn pair(&self, coin: &str) -> Option<&SpotPair> {
+        self.universe.iter().find(|pair| pair.name == coin)
+    }
+
+    /// Base and quote tokens of a pair
+    pub fn pair_tokens(&self, pair: &SpotPair) -> Option<(&SpotToken, &SpotToken)> {
+        Some((self.token(pair.tokens[0])?, self.token(pair.tokens[1])?))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotToken {
+    pub name: String,
+    /// Number of decimals allowed in order sizes
+    pub sz_decimals: u32,
+    /// Number of decimals of the token's on-chain amounts
+    pub wei_decimals: u32,
+    pub index: u32,
+    /// 16-byte token id, as hex
+    pub token_id: String,
+    pub is_canonical: bool,
+    #[serde(default)]
+    pub evm_contract: Option<EvmContract>,
+    #[serde(default)]
+    pub full_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EvmContract {
+    pub address: Address,
+    pub evm_extra_wei_decimals: i32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotPair {
+    /// Coin name used in requests and subscriptions: `PURR/USDC` for canonical pairs,
+    /// `@N` otherwise
+    pub name: String,
+    /// Base and quote token indices
+    pub tokens: [u32; 2],
+    pub index: u32,
+    pub is_canonical: bool,
+}
+
+impl SpotPair {
+    /// Asset id of the pair, as used in [`crate::exchange::order::OrderRequest::asset`]
+    pub fn asset_id(&self) -> u32 {
+        SPOT_ASSET_OFFSET + self.index
+    }
+}
+
+/// Live context of a spot pair
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotAssetCtx {
+    /// Pair name, matching [`SpotPair::name`]
+    pub coin: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub prev_day_px: Decimal,
+    /// 24h notional volume
+    #[serde(with = "rust_decimal::serde::str")]
+    pub day_ntl_vlm: Decimal,
+    /// 24h volume in units of the base token
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    pub day_base_vlm: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub mark_px: Decimal,
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    pub mid_px: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub circulating_supply: Decimal,
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    pub total_supply: Option<Decimal>,
+}
+
+/// [`SpotMeta`] together with one [`SpotAssetCtx`] per pair
+pub type SpotMetaAndAssetCtxsResponse = (SpotMeta, Vec<SpotAssetCtx>);
+
+impl HyperLiquidClient {
+    /// Get spot tokens and trading pairs
+    pub async fn get_spot_meta(&self) -> Result<SpotMeta> {
+        self.make_basic_request("spotMeta").await
+    }
+
+    /// Get spot tokens and trading pairs with prices, volume and supply of every pair
+    pub async fn get_spot_meta_and_asset_ctxs(&self) -> Result<SpotMetaAndAssetCtxsResponse> {
+        self.make_basic_request("spotMetaAndAssetCtxs").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spot_meta_lookups() {
+        let meta: SpotMeta = serde_json::from_value(serde_json::json!({
+            "tokens": [
+                {"name": "USDC", "szDecimals": 8, "weiDecimals": 8, "index": 0,
+                 "tokenId": "0x6d1e7cde53ba9467b783cb7c530ce054", "isCanonical": true,
+                 "evmContract": null, "fullName": null},
+                {"name": "PURR", "szDecimals": 0, "weiDecimals": 5, "index": 1,
+                 "tokenId": "0xc1fb593aeffbeb02f85e0308e9956a90", "isCanonical": true,
+                 "evmContract": {"address": "0x9b498c3c8a0b8cd8ba1d9851d40d186f1872b44e", "evm_extra_wei_decimals": -2},
+                 "fullName": "Purr"}
+            ],
+            "universe": [
+                {"name": "PURR/USDC", "tokens": [1, 0], "index": 0, "isCanonical": true},
+                {"name": "@1", "tokens": [1, 0], "index": 1, "isCanonical": false}
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(meta.token(1).unwrap().wei_decimals, 5);
+        assert_eq!(meta.token(1).unwrap().evm_contract.as_ref().unwrap().evm_extra_wei_decimals, -2);
+        assert_eq!(meta.pair("@1").unwrap().asset_id(), 10_001);
+
+        let (base, quote) = meta.pair_tokens(meta.pair("PURR/USDC").unwrap()).unwrap();
+        assert_eq!((base.name.as_str(), quote.name.as_str()), ("PURR", "USDC"));
+    }
+}
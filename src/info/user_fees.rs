@@ -1,7 +1,7 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
-use crate::{client::HyperLiquidClient, errors::validate_ethereum_address};
+use crate::{address::IntoAddress, client::HyperLiquidClient, errors::Result};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -103,8 +103,7 @@ pub struct ActiveStakingDiscount {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_user_fees(&self, user: &str) -> anyhow::Result<UserFeesResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_user_fees(&self, user: impl IntoAddress) -> Result<UserFeesResponse> {
         self.make_user_request("userFees", user).await
     }
 }
\ No newline at end of file
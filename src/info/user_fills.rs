@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,8 +36,7 @@ pub struct UserFillsResponse {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_user_fills(&self, user: &str) -> anyhow::Result<Vec<UserFillsResponse>> {
-        validate_ethereum_address(user)?;
+    pub async fn get_user_fills(&self, user: impl IntoAddress) -> Result<Vec<UserFillsResponse>> {
         self.make_user_request("userFills", user).await
     }
 }
\ No newline at end of file
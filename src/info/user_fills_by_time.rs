@@ -1,14 +1,15 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::address::{Address, IntoAddress};
 use crate::client::HyperLiquidClient;
-use crate::errors::{validate_ethereum_address, validate_time_range};
+use crate::errors::{validate_time_range, Result};
 
 #[derive(Serialize)]
 struct UserFillsByTimeRequest {
     #[serde(rename = "type")]
     request_type: String,
-    user: String,
+    user: Address,
     #[serde(rename = "startTime")]
     start_time: u64,
     #[serde(rename = "endTime")]
@@ -46,25 +47,25 @@ pub struct UserFillsByTimeResponse {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Liquidation {
-    pub liquidated_user: String,
+    pub liquidated_user: Address,
     #[serde(with = "rust_decimal::serde::str")]
     pub mark_px: Decimal,
     pub method: String,
 }
 
 impl HyperLiquidClient {
-    pub async fn get_user_fills_by_time(&self, user: &str, start_time: u64, end_time: Option<u64>, aggregated_by_time: bool) -> anyhow::Result<Vec<UserFillsByTimeResponse>> {
-        validate_ethereum_address(user)?;
+    pub async fn get_user_fills_by_time(&self, user: impl IntoAddress, start_time: u64, end_time: Option<u64>, aggregated_by_time: bool) -> Result<Vec<UserFillsByTimeResponse>> {
+        let user = user.into_address()?;
         if let Some(end_time) = end_time {
             validate_time_range(start_time, end_time)?;
         }
         
         let request_body = UserFillsByTimeRequest {
             request_type: "userFillsByTime".to_string(),
-            user: user.to_string(),
-            start_time: start_time,
-            end_time: end_time,
-            aggregated_by_time: aggregated_by_time,
+            user,
+            start_time,
+            end_time,
+            aggregated_by_time,
         };
 
         self.make_custom_request(&request_body).await
@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,8 +15,7 @@ pub struct UserRateLimitResponse {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_user_rate_limit(&self, user: &str) -> anyhow::Result<UserRateLimitResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_user_rate_limit(&self, user: impl IntoAddress) -> Result<UserRateLimitResponse> {
         self.make_user_request("userRateLimit", user).await
     }
 }
\ No newline at end of file
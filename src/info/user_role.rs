@@ -1,7 +1,8 @@
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 #[derive(Debug, Deserialize)]
 pub struct UserRoleResponse {
@@ -19,8 +20,7 @@ pub enum Role {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_user_role(&self, user: &str) -> anyhow::Result<UserRoleResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_user_role(&self, user: impl IntoAddress) -> Result<UserRoleResponse> {
         self.make_user_request("userRole", user).await
     }
 }
\ No newline at end of file
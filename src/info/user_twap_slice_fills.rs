@@ -1,8 +1,9 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::IntoAddress;
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -36,8 +37,7 @@ pub struct Fill {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_user_twap_slice_fills(&self, user: &str) -> anyhow::Result<Vec<UserTwapSliceFillsResponse>> {
-        validate_ethereum_address(user)?;
+    pub async fn get_user_twap_slice_fills(&self, user: impl IntoAddress) -> Result<Vec<UserTwapSliceFillsResponse>> {
         self.make_user_request("userTwapSliceFills", user).await
     }
 }
\ No newline at end of file
@@ -1,23 +1,23 @@
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
+use crate::address::{Address, IntoAddress};
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 pub type UserVaultEquitiesResponse = Option<Vec<VaultPosition>>;
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VaultPosition {
-    pub vault_address: String,
+    pub vault_address: Address,
     #[serde(with = "rust_decimal::serde::str")]
     pub equity: Decimal,
     pub locked_until_timestamp: u64,
 }
 
 impl HyperLiquidClient {
-    pub async fn get_user_vault_equities(&self, user: &str) -> anyhow::Result<UserVaultEquitiesResponse> {
-        validate_ethereum_address(user)?;
+    pub async fn get_user_vault_equities(&self, user: impl IntoAddress) -> Result<UserVaultEquitiesResponse> {
         self.make_user_request("userVaultEquities", user).await
     }
 }
\ No newline at end of file
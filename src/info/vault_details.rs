@@ -1,15 +1,16 @@
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::address::{Address, IntoAddress};
 use crate::client::HyperLiquidClient;
-use crate::errors::validate_ethereum_address;
+use crate::errors::Result;
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct VaultDetailsRequest {
     #[serde(rename = "type")]
     request_type: String,
-    vault_address: String,
+    vault_address: Address,
 }
 
 pub type VaultDetailsResponse = Option<VaultDetails>;
@@ -18,8 +19,8 @@ pub type VaultDetailsResponse = Option<VaultDetails>;
 #[serde(rename_all = "camelCase")]
 pub struct VaultDetails {
     pub name: String,
-    pub vault_address: String,
-    pub leader: String,
+    pub vault_address: Address,
+    pub leader: Address,
     pub description: String,
     pub portfolio: Vec<(String, PortfolioData)>,
     #[serde(with = "rust_decimal::serde::str")]
@@ -55,7 +56,7 @@ pub struct HistoryEntry(pub u64, #[serde(with = "rust_decimal::serde::str")] pub
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Follower {
-    pub user: String,
+    pub user: FollowerUser,
     #[serde(with = "rust_decimal::serde::str")]
     pub vault_equity: Decimal,
     #[serde(with = "rust_decimal::serde::str")]
@@ -67,6 +68,33 @@ pub struct Follower {
     pub lockup_until: u64,
 }
 
+/// Owner of a follower row; the vault leader's own row reports `"Leader"` instead of an address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FollowerUser {
+    Leader,
+    Address(Address),
+}
+
+impl FollowerUser {
+    /// The follower's address, or `None` for the leader's row (see [`VaultDetails::leader`])
+    pub fn address(&self) -> Option<Address> {
+        match self {
+            FollowerUser::Leader => None,
+            FollowerUser::Address(address) => Some(*address),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FollowerUser {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let user = String::deserialize(deserializer)?;
+        if user == "Leader" {
+            return Ok(FollowerUser::Leader);
+        }
+        user.parse().map(FollowerUser::Address).map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Relationship {
@@ -75,14 +103,45 @@ pub struct Relationship {
 }
 
 impl HyperLiquidClient {
-    pub async fn get_vault_details(&self, vault_address: &str) -> anyhow::Result<VaultDetailsResponse> {
-        validate_ethereum_address(vault_address)?;
+    pub async fn get_vault_details(&self, vault_address: impl IntoAddress) -> Result<VaultDetailsResponse> {
+        let vault_address = vault_address.into_address()?;
         
         let request_body = VaultDetailsRequest {
             request_type: "vaultDetails".to_string(),
-            vault_address: vault_address.to_string(),
+            vault_address,
         };
 
         self.make_custom_request(&request_body).await
     }
-}   
\ No newline at end of file
+}   
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_followers_include_leader_row() {
+        let follower = |user: &str| serde_json::json!({
+            "user": user,
+            "vaultEquity": "100.5",
+            "pnl": "1.5",
+            "allTimePnl": "2.5",
+            "daysFollowing": 3,
+            "vaultEntryTime": 1,
+            "lockupUntil": 2
+        });
+        let followers: Vec<Follower> = serde_json::from_value(serde_json::json!([
+            follower("Leader"),
+            follower("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+        ]))
+        .unwrap();
+
+        assert_eq!(followers[0].user, FollowerUser::Leader);
+        assert_eq!(followers[0].user.address(), None);
+        assert_eq!(
+            followers[1].user.address(),
+            Some("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse().unwrap())
+        );
+        assert!(serde_json::from_value::<Follower>(follower("0x0")).is_err());
+    }
+}
@@ -9,14 +9,14 @@
 //! - **Type Safety**: Fully typed requests and responses with serde
 //! - **Input Validation**: Validates Ethereum addresses, coin symbols, intervals, and time ranges
 //! - **Retry Logic**: Automatic retries with exponential backoff for failed requests
-//! - **Rate Limiting**: Built-in handling for API rate limits (HTTP 429)
+//! - **Rate Limiting**: Built-in handling for API rate limits (HTTP 429) and optional weighted client-side throttling
 //! - **Connection Pooling**: Efficient HTTP connection reuse and keep-alive
 //! - **Error Handling**: Comprehensive error types with context
 //! - **Testing**: Extensive unit and integration tests
 //! 
 //! ## Quick Start
 //! 
-//! ```rust
+//! ```rust,no_run
 //! use hyperliquid::client::HyperLiquidClient;
 //! 
 //! #[tokio::main]
@@ -33,11 +33,33 @@
 //! 
 //! ## Modules
 //! 
+//! - [`action_budget`] - Counts exchange actions against the address's request cap
+//! - [`address`] - Ethereum address type with EIP-55 checksums, accepted by every endpoint
+//! - `blocking` - Synchronous client mirroring every `get_*` method (`blocking` feature)
 //! - [`client`] - Main HyperLiquid client with connection pooling and retry logic
-//! - [`config`] - Configuration management with builder pattern and environment variables
+//! - [`config`] - Configuration management with builder pattern, environment variables and files
 //! - [`errors`] - Error types and input validation functions
+//! - `metrics` - Request and WebSocket metrics in the Prometheus text format (`metrics` feature)
+//! - [`middleware`] - Hooks around every request, with logging and timing built in
+//! - [`network`] - Mainnet, testnet and custom deployments with their URLs and signing constants
+//! - [`rate_limiter`] - Weighted token bucket that throttles requests before they hit 429s
+//! - [`recording`] - Records `/info` traffic as JSON fixtures and replays it without a network
+//! - [`retry`] - Retry policy with configurable backoff, jitter and `Retry-After` handling
+//! - [`transport`] - Pluggable HTTP transport, including an in-memory one for tests
 //! - [`types`] - Common types shared across API endpoints
 //! - [`info`] - API endpoint implementations for all HyperLiquid endpoints
+//! - [`exchange`] - Signed trading actions sent to the `/exchange` endpoint
+//! - [`ws`] - WebSocket subscriptions for real-time market and user data
+
+/// Address-based action budget tracking seeded from `userRateLimit`
+pub mod action_budget;
+
+/// Ethereum address type with checksum validation
+pub mod address;
+
+/// Synchronous client for code without an async runtime
+#[cfg(feature = "blocking")]
+pub mod blocking;
 
 /// Core client functionality with connection pooling and retry logic
 pub mod client;
@@ -51,6 +73,28 @@ pub mod config;
 /// Error handling and input validation
 pub mod errors;
 
+/// Prometheus-style request and WebSocket metrics
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+/// Request/response hooks with built-in logging and timing middleware
+pub mod middleware;
+
+/// Network presets tying URLs to signing constants
+pub mod network;
+
+/// Client-side throttling against the per-IP request weight budget
+pub mod rate_limiter;
+
+/// Recording and replay of `/info` traffic as JSON fixtures
+pub mod recording;
+
+/// Backoff, jitter and retryability rules for HTTP requests
+pub mod retry;
+
+/// Pluggable HTTP transport with reqwest and in-memory implementations
+pub mod transport;
+
 /// Common types used across multiple API endpoints
 pub mod types;
 
@@ -62,9 +106,14 @@ pub mod types;
 /// - [`all_mids`] - Get mid prices for all trading pairs
 /// - [`candle_snapshot`] - Get OHLCV candle data with time range filtering  
 /// - [`l2_book`] - Get order book depth data
+/// - [`meta`] - Get the perpetuals universe, margin tables and asset contexts
+/// - [`funding`] - Get funding rate history and predicted fundings across venues
+/// - [`spot_meta`] - Get spot tokens, trading pairs and pair contexts
 /// 
 /// ## User Account Data
 /// - [`portfolio`] - Get portfolio history and PnL data
+/// - [`clearinghouse_state`] - Get margin summary, open positions and spot balances
+/// - [`ledger_updates`] - Get deposits, withdrawals, transfers and liquidations
 /// - [`open_orders`] & [`frontend_open_orders`] - Get active order information
 /// - [`historical_orders`] - Get order history with detailed status
 /// - [`user_fills`] & [`user_fills_by_time`] - Get trade fill data
@@ -86,6 +135,8 @@ pub mod info {
     pub mod all_mids;
     /// Get OHLCV candle data with time range filtering
     pub mod candle_snapshot;
+    /// Get perpetuals margin summary, positions and spot balances
+    pub mod clearinghouse_state;
     /// Get delegation/staking information
     pub mod delegations;
     /// Get delegator history
@@ -96,12 +147,18 @@ pub mod info {
     pub mod delegator_summary;
     /// Get frontend-specific open orders
     pub mod frontend_open_orders;
+    /// Get user funding payments, funding rate history and predicted fundings
+    pub mod funding;
     /// Get historical orders with detailed status
     pub mod historical_orders;
     /// Get order book depth data
     pub mod l2_book;
+    /// Get deposits, withdrawals, transfers and other non-funding ledger updates
+    pub mod ledger_updates;
     /// Get maximum builder fee calculations
     pub mod max_builder_fee;
+    /// Get perpetuals metadata and asset contexts
+    pub mod meta;
     /// Get portfolio history and PnL data
     pub mod portfolio;
     /// Get referral program information
@@ -110,6 +167,8 @@ pub mod info {
     pub mod open_orders;
     /// Get individual order status
     pub mod order_status;
+    /// Get spot metadata and pair contexts
+    pub mod spot_meta;
     /// Get sub-account information
     pub mod sub_accounts;
     /// Get user fee structure and discounts
@@ -128,4 +187,42 @@ pub mod info {
     pub mod user_vault_equities;
     /// Get vault details and information
     pub mod vault_details;
-}
\ No newline at end of file
+}
+
+/// Signed trading actions sent to the `/exchange` endpoint
+/// 
+/// Actions are msgpack-encoded, hashed with a nonce and optional vault address, and
+/// signed with a local secp256k1 key using Hyperliquid's EIP-712 phantom agent scheme.
+/// 
+/// - [`signing`] - Action hashing and EIP-712 signing
+/// - [`action`] - Signed request envelope and response handling
+/// - [`order`] - Typed order placement
+/// - [`cancel`] & [`modify`] - Cancel and modify resting orders
+pub mod exchange {
+    /// Signed request envelope and response handling
+    pub mod action;
+    /// Cancel orders by order id or client order id
+    pub mod cancel;
+    /// Modify resting orders individually or in batches
+    pub mod modify;
+    /// Typed order placement
+    pub mod order;
+    /// Action hashing and EIP-712 signing
+    pub mod signing;
+}
+
+/// WebSocket subscriptions for real-time market and user data
+/// 
+/// - [`client`] - Connection handling and subscription management
+/// - [`subscription`] - Subscription descriptors
+/// - [`message`] - Typed messages pushed by the server
+pub mod ws {
+    /// Connection handling and subscription management
+    pub mod client;
+    /// Opening the socket with the configured proxy, root certificates and address overrides
+    pub(crate) mod connect;
+    /// Typed messages pushed by the server
+    pub mod message;
+    /// Subscription descriptors
+    pub mod subscription;
+}
@@ -0,0 +1,229 @@
+//! Request and WebSocket metrics in the Prometheus text exposition format.
+//!
+//! With the `metrics` feature every client records into a process-wide [`Registry`]:
+//!
+//! - `hyperliquid_requests_total{type, status}` - responses and transport failures per
+//!   attempt, by request type and status class (`2xx`, `4xx`, `5xx`, `error`)
+//! - `hyperliquid_request_duration_seconds{type}` - latency of each attempt
+//! - `hyperliquid_retries_total{type}` - retries scheduled
+//! - `hyperliquid_rate_limited_total{type}` - HTTP 429 responses
+//! - `hyperliquid_decode_errors_total{type}` - successful responses that failed to decode
+//! - `hyperliquid_ws_messages_total{channel}` - WebSocket messages received
+//! - `hyperliquid_ws_reconnects_total` - WebSocket connections re-established
+//!
+//! [`render`] returns all of them as text, ready to be served from a `/metrics` endpoint.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Upper bounds, in seconds, of the request duration histogram buckets
+pub const DURATION_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+const HELP: [(&str, &str); 7] = [
+    ("hyperliquid_decode_errors_total", "Successful responses that could not be decoded"),
+    ("hyperliquid_rate_limited_total", "Responses with HTTP status 429"),
+    ("hyperliquid_request_duration_seconds", "Latency of each request attempt"),
+    ("hyperliquid_requests_total", "Request attempts by request type and status class"),
+    ("hyperliquid_retries_total", "Retries scheduled after a failed attempt"),
+    ("hyperliquid_ws_messages_total", "WebSocket messages received by channel"),
+    ("hyperliquid_ws_reconnects_total", "WebSocket connections re-established"),
+];
+
+type Labels = Vec<(&'static str, String)>;
+type Key = (&'static str, Labels);
+
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Non-cumulative count per bucket of [`DURATION_BUCKETS`]
+    buckets: [u64; DURATION_BUCKETS.len()],
+    count: u64,
+    sum: f64,
+}
+
+/// Counters and histograms recorded by the client
+#[derive(Debug, Default)]
+pub struct Registry {
+    counters: Mutex<BTreeMap<Key, u64>>,
+    histograms: Mutex<BTreeMap<Key, Histogram>>,
+}
+
+/// The process-wide registry clients record into
+pub fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::default)
+}
+
+/// Render the process-wide registry in the Prometheus text exposition format
+pub fn render() -> String {
+    registry().render()
+}
+
+/// Status class label of an HTTP status
+fn status_class(status: u16) -> String {
+    format!("{}xx", status / 100)
+}
+
+impl Registry {
+    /// Record the response to one attempt
+    pub(crate) fn request(&self, request_type: &str, status: u16, latency: Duration) {
+        self.increment("hyperliquid_requests_total", vec![("type", request_type.to_string()), ("status", status_class(status))]);
+        self.observe("hyperliquid_request_duration_seconds", vec![("type", request_type.to_string())], latency);
+        if status == 429 {
+            self.increment("hyperliquid_rate_limited_total", vec![("type", request_type.to_string())]);
+        }
+    }
+
+    /// Record an attempt that failed before a response arrived
+    pub(crate) fn request_error(&self, request_type: &str) {
+        self.increment("hyperliquid_requests_total", vec![("type", request_type.to_string()), ("status", "error".to_string())]);
+    }
+
+    pub(crate) fn retry(&self, request_type: &str) {
+        self.increment("hyperliquid_retries_total", vec![("type", request_type.to_string())]);
+    }
+
+    pub(crate) fn decode_error(&self, request_type: &str) {
+        self.increment("hyperliquid_decode_errors_total", vec![("type", request_type.to_string())]);
+    }
+
+    pub(crate) fn ws_message(&self, channel: &str) {
+        self.increment("hyperliquid_ws_messages_total", vec![("channel", channel.to_string())]);
+    }
+
+    pub(crate) fn ws_reconnect(&self) {
+        self.increment("hyperliquid_ws_reconnects_total", Vec::new());
+    }
+
+    /// Current value of a counter, 0 if it was never incremented
+    pub fn counter(&self, name: &str, labels: &[(&str, &str)]) -> u64 {
+        let counters = self.counters.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        counters
+            .iter()
+            .find(|((key_name, key_labels), _)| {
+                *key_name == name
+                    && key_labels.len() == labels.len()
+                    && key_labels.iter().zip(labels).all(|((k1, v1), (k2, v2))| k1 == k2 && v1 == v2)
+            })
+            .map_or(0, |(_, value)| *value)
+    }
+
+    fn increment(&self, name: &'static str, labels: Labels) {
+        *self
+            .counters
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry((name, labels))
+            .or_default() += 1;
+    }
+
+    fn observe(&self, name: &'static str, labels: Labels, value: Duration) {
+        let seconds = value.as_secs_f64();
+        let mut histograms = self.histograms.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let histogram = histograms.entry((name, labels)).or_default();
+        if let Some(bucket) = DURATION_BUCKETS.iter().position(|bound| seconds <= *bound) {
+            histogram.buckets[bucket] += 1;
+        }
+        histogram.count += 1;
+        histogram.sum += seconds;
+    }
+
+    /// Render every metric in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+
+        let counters = self.counters.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        let mut previous = None;
+        for ((name, labels), value) in &counters {
+            if previous != Some(*name) {
+                write_header(&mut output, name, "counter");
+                previous = Some(*name);
+            }
+            let _ = writeln!(output, "{}{} {}", name, format_labels(labels, None), value);
+        }
+
+        let histograms = self.histograms.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+        let mut previous = None;
+        for ((name, labels), histogram) in &histograms {
+            if previous != Some(*name) {
+                write_header(&mut output, name, "histogram");
+                previous = Some(*name);
+            }
+            let mut cumulative = 0;
+            for (bound, count) in DURATION_BUCKETS.iter().zip(histogram.buckets) {
+                cumulative += count;
+                let _ = writeln!(output, "{}_bucket{} {}", name, format_labels(labels, Some(&bound.to_string())), cumulative);
+            }
+            let _ = writeln!(output, "{}_bucket{} {}", name, format_labels(labels, Some("+Inf")), histogram.count);
+            let _ = writeln!(output, "{}_sum{} {}", name, format_labels(labels, None), histogram.sum);
+            let _ = writeln!(output, "{}_count{} {}", name, format_labels(labels, None), histogram.count);
+        }
+
+        output
+    }
+}
+
+fn write_header(output: &mut String, name: &str, metric_type: &str) {
+    if let Some((_, help)) = HELP.iter().find(|(metric, _)| *metric == name) {
+        let _ = writeln!(output, "# HELP {} {}", name, help);
+    }
+    let _ = writeln!(output, "# TYPE {} {}", name, metric_type);
+}
+
+/// `{key="value",...}`, with an `le` label for histogram buckets; empty without labels
+fn format_labels(labels: &Labels, le: Option<&str>) -> String {
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+        .collect();
+    if let Some(le) = le {
+        pairs.push(format!("le=\"{}\"", le));
+    }
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_counters_and_histograms() {
+        let registry = Registry::default();
+        registry.request("l2Book", 200, Duration::from_millis(20));
+        registry.request("l2Book", 429, Duration::from_millis(3));
+        registry.retry("l2Book");
+        registry.ws_message("trades");
+        registry.ws_reconnect();
+
+        assert_eq!(registry.counter("hyperliquid_requests_total", &[("type", "l2Book"), ("status", "2xx")]), 1);
+        assert_eq!(registry.counter("hyperliquid_rate_limited_total", &[("type", "l2Book")]), 1);
+
+        let output = registry.render();
+        assert!(output.contains("# TYPE hyperliquid_requests_total counter\n"));
+        assert!(output.contains("hyperliquid_requests_total{type=\"l2Book\",status=\"4xx\"} 1\n"));
+        assert!(output.contains("hyperliquid_retries_total{type=\"l2Book\"} 1\n"));
+        assert!(output.contains("hyperliquid_ws_messages_total{channel=\"trades\"} 1\n"));
+        assert!(output.contains("hyperliquid_ws_reconnects_total 1\n"));
+        assert!(output.contains("# TYPE hyperliquid_request_duration_seconds histogram\n"));
+        assert!(output.contains("hyperliquid_request_duration_seconds_bucket{type=\"l2Book\",le=\"0.005\"} 1\n"));
+        assert!(output.contains("hyperliquid_request_duration_seconds_bucket{type=\"l2Book\",le=\"0.025\"} 2\n"));
+        assert!(output.contains("hyperliquid_request_duration_seconds_bucket{type=\"l2Book\",le=\"+Inf\"} 2\n"));
+        assert!(output.contains("hyperliquid_request_duration_seconds_count{type=\"l2Book\"} 2\n"));
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        let registry = Registry::default();
+        registry.ws_message("a\"b");
+        assert!(registry.render().contains("channel=\"a\\\"b\""));
+    }
+}
@@ -0,0 +1,264 @@
+//! Hooks around every REST request.
+//!
+//! A [`Middleware`] registered with [`crate::config::HyperLiquidConfig::with_middleware`]
+//! sees each attempt of every `make_*_request` call, retries included: it may add headers
+//! before a request is sent, inspect or rewrite responses, and observe retries and
+//! failures. [`LoggingMiddleware`] and [`TimingMiddleware`] cover the common cases.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::errors::HyperLiquidError;
+use crate::transport::{TransportRequest, TransportResponse};
+
+/// Hooks invoked by the client around requests, in registration order
+///
+/// Every hook has an empty default, so implementations only override what they need.
+pub trait Middleware: Send + Sync + fmt::Debug {
+    /// Before every attempt, including retries; may modify the request, e.g. its headers
+    fn before_send(&self, _request: &mut TransportRequest) {}
+
+    /// For every response received, before its status is checked or its body decoded
+    fn after_receive(&self, _request: &TransportRequest, _response: &mut TransportResponse, _latency: Duration) {}
+
+    /// Before waiting `delay` to send retry number `retry` (starting at 1)
+    fn on_retry(&self, _request: &TransportRequest, _retry: u32, _delay: Duration) {}
+
+    /// Once, when the call fails with `error`
+    fn on_error(&self, _request: &TransportRequest, _error: &HyperLiquidError) {}
+}
+
+impl<T: Middleware + ?Sized> Middleware for Arc<T> {
+    fn before_send(&self, request: &mut TransportRequest) {
+        (**self).before_send(request)
+    }
+
+    fn after_receive(&self, request: &TransportRequest, response: &mut TransportResponse, latency: Duration) {
+        (**self).after_receive(request, response, latency)
+    }
+
+    fn on_retry(&self, request: &TransportRequest, retry: u32, delay: Duration) {
+        (**self).on_retry(request, retry, delay)
+    }
+
+    fn on_error(&self, request: &TransportRequest, error: &HyperLiquidError) {
+        (**self).on_error(request, error)
+    }
+}
+
+type LogSink = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Writes a line per request, response, retry and failure
+///
+/// Lines go to stderr unless another sink is given. Addresses in logged bodies are
+/// shortened to `0x1234…abcd` unless redaction is turned off.
+///
+/// # Examples
+///
+/// ```rust
+/// use hyperliquid::config::HyperLiquidConfig;
+/// use hyperliquid::middleware::{LoggingMiddleware, TimingMiddleware};
+///
+/// let config = HyperLiquidConfig::new()
+///     .with_middleware(LoggingMiddleware::new().with_bodies(true))
+///     .with_middleware(TimingMiddleware::new());
+/// ```
+#[derive(Clone)]
+pub struct LoggingMiddleware {
+    sink: LogSink,
+    log_bodies: bool,
+    redact_addresses: bool,
+}
+
+impl fmt::Debug for LoggingMiddleware {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoggingMiddleware")
+            .field("log_bodies", &self.log_bodies)
+            .field("redact_addresses", &self.redact_addresses)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for LoggingMiddleware {
+    fn default() -> Self {
+        Self {
+            sink: Arc::new(|line| eprintln!("{}", line)),
+            log_bodies: false,
+            redact_addresses: true,
+        }
+    }
+}
+
+impl LoggingMiddleware {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send lines somewhere other than stderr
+    pub fn with_sink(mut self, sink: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.sink = Arc::new(sink);
+        self
+    }
+
+    /// Include request and response bodies
+    pub fn with_bodies(mut self, log_bodies: bool) -> Self {
+        self.log_bodies = log_bodies;
+        self
+    }
+
+    pub fn with_redacted_addresses(mut self, redact_addresses: bool) -> Self {
+        self.redact_addresses = redact_addresses;
+        self
+    }
+
+    fn log(&self, line: String, body: Option<&str>) {
+        let line = match body {
+            Some(body) if self.log_bodies => format!("{} {}", line, body),
+            _ => line,
+        };
+        if self.redact_addresses {
+            (self.sink)(&redact_addresses(&line));
+        } else {
+            (self.sink)(&line);
+        }
+    }
+}
+
+impl Middleware for LoggingMiddleware {
+    fn before_send(&self, request: &mut TransportRequest) {
+        self.log(format!("--> POST {} {}", request.path(), request_label(request)), Some(&request.body));
+    }
+
+    fn after_receive(&self, request: &TransportRequest, response: &mut TransportResponse, latency: Duration) {
+        self.log(
+            format!(
+                "<-- {} {} {} in {}ms",
+                response.status,
+                request.path(),
+                request_label(request),
+                latency.as_millis()
+            ),
+            Some(&response.body),
+        );
+    }
+
+    fn on_retry(&self, request: &TransportRequest, retry: u32, delay: Duration) {
+        self.log(
+            format!("retry {} of {} in {}ms", retry, request_label(request), delay.as_millis()),
+            None,
+        );
+    }
+
+    fn on_error(&self, request: &TransportRequest, error: &HyperLiquidError) {
+        self.log(format!("{} failed: {}", request_label(request), error), None);
+    }
+}
+
+fn request_label(request: &TransportRequest) -> String {
+    request.request_type().unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Shorten every `0x`-prefixed 40 hex digit address in `text` to `0x1234…abcd`
+pub fn redact_addresses(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut redacted = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut index = 0;
+
+    while index + 42 <= bytes.len() {
+        let is_address = bytes[index] == b'0'
+            && bytes[index + 1] == b'x'
+            && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric())
+            && bytes[index + 2..index + 42].iter().all(u8::is_ascii_hexdigit)
+            && bytes.get(index + 42).is_none_or(|next| !next.is_ascii_hexdigit());
+        if is_address {
+            redacted.push_str(&text[copied..index + 6]);
+            redacted.push('…');
+            redacted.push_str(&text[index + 38..index + 42]);
+            index += 42;
+            copied = index;
+        } else {
+            index += 1;
+        }
+    }
+    redacted.push_str(&text[copied..]);
+    redacted
+}
+
+/// Latency of the responses to one request type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatencyStats {
+    pub count: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.count as u32
+        }
+    }
+}
+
+/// Collects response latency per request type
+///
+/// Keep an `Arc` of the middleware to read [`TimingMiddleware::stats`] after registering it.
+#[derive(Debug, Default)]
+pub struct TimingMiddleware {
+    stats: Mutex<HashMap<String, LatencyStats>>,
+}
+
+impl TimingMiddleware {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Latency so far, keyed by request type
+    pub fn stats(&self) -> HashMap<String, LatencyStats> {
+        self.stats.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+}
+
+impl Middleware for TimingMiddleware {
+    fn after_receive(&self, request: &TransportRequest, _response: &mut TransportResponse, latency: Duration) {
+        let mut stats = self.stats.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let entry = stats.entry(request_label(request)).or_default();
+        entry.count += 1;
+        entry.total += latency;
+        entry.max = entry.max.max(latency);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_addresses() {
+        let text = r#"{"user":"0x1234567890123456789012345678901234567890","oid":1}"#;
+        assert_eq!(redact_addresses(text), r#"{"user":"0x1234…7890","oid":1}"#);
+
+        // Longer hex strings such as hashes are left alone
+        let hash = "0x1234567890123456789012345678901234567890123456789012345678901234";
+        assert_eq!(redact_addresses(hash), hash);
+        assert_eq!(redact_addresses("no addresses"), "no addresses");
+    }
+
+    #[test]
+    fn test_latency_stats() {
+        let timing = TimingMiddleware::new();
+        let request = TransportRequest::new("http://localhost/info", r#"{"type":"allMids"}"#);
+        let mut response = TransportResponse::new(200, "{}");
+
+        timing.after_receive(&request, &mut response, Duration::from_millis(10));
+        timing.after_receive(&request, &mut response, Duration::from_millis(30));
+
+        let stats = timing.stats()["allMids"];
+        assert_eq!((stats.count, stats.max, stats.mean()), (2, Duration::from_millis(30), Duration::from_millis(20)));
+    }
+}
@@ -0,0 +1,197 @@
+//! HyperLiquid deployments and the constants that differ between them.
+//!
+//! A [`Network`] fixes the REST and WebSocket URLs together with how actions are signed
+//! for them, so a configuration cannot sign mainnet actions while talking to testnet or
+//! the other way around. Select one with [`crate::config::HyperLiquidConfig::with_network`]
+//! or the `HYPERLIQUID_NETWORK` environment variable.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::config::{MAINNET_API_URL, MAINNET_WS_URL, TESTNET_API_URL, TESTNET_WS_URL};
+use crate::errors::HyperLiquidError;
+
+/// REST base URL of a local node serving the info API
+pub const LOCAL_API_URL: &str = "http://localhost:3001";
+
+/// EIP-712 chain id of user-signed actions on mainnet (Arbitrum One)
+pub const MAINNET_SIGNATURE_CHAIN_ID: u64 = 0xa4b1;
+
+/// EIP-712 chain id of user-signed actions on testnet (Arbitrum Sepolia)
+pub const TESTNET_SIGNATURE_CHAIN_ID: u64 = 0x66eee;
+
+/// A HyperLiquid deployment
+///
+/// # Examples
+///
+/// ```rust
+/// use hyperliquid::config::HyperLiquidConfig;
+/// use hyperliquid::network::Network;
+///
+/// let config = HyperLiquidConfig::new().with_network(Network::Testnet);
+/// assert_eq!(config.base_url, "https://api.hyperliquid-testnet.xyz");
+/// assert!(!config.is_mainnet());
+///
+/// let network: Network = "mainnet".parse().unwrap();
+/// assert_eq!(network.phantom_agent_source(), "a");
+/// assert_eq!(network.hyperliquid_chain(), "Mainnet");
+/// assert_eq!(network.signature_chain_id(), 0xa4b1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Network {
+    #[default]
+    Mainnet,
+    Testnet,
+    /// A local node or mock server; actions are signed as on testnet
+    Local { base_url: String },
+    /// Any other endpoint, e.g. a proxy, in front of mainnet (`mainnet: true`) or testnet
+    Custom {
+        base_url: String,
+        ws_url: String,
+        mainnet: bool,
+    },
+}
+
+impl Network {
+    /// Network a base URL belongs to: a preset for the public URLs, otherwise a
+    /// [`Network::Custom`] signing as testnet
+    pub fn from_base_url(base_url: &str, ws_url: &str) -> Self {
+        match base_url.trim_end_matches('/') {
+            MAINNET_API_URL => Network::Mainnet,
+            TESTNET_API_URL => Network::Testnet,
+            _ => Network::Custom {
+                base_url: base_url.to_string(),
+                ws_url: ws_url.to_string(),
+                mainnet: false,
+            },
+        }
+    }
+
+    /// REST base URL
+    pub fn base_url(&self) -> &str {
+        match self {
+            Network::Mainnet => MAINNET_API_URL,
+            Network::Testnet => TESTNET_API_URL,
+            Network::Local { base_url } | Network::Custom { base_url, .. } => base_url,
+        }
+    }
+
+    /// WebSocket URL; `/ws` on the same host for [`Network::Local`]
+    pub fn ws_url(&self) -> String {
+        match self {
+            Network::Mainnet => MAINNET_WS_URL.to_string(),
+            Network::Testnet => TESTNET_WS_URL.to_string(),
+            Network::Local { base_url } => {
+                format!("{}/ws", base_url.trim_end_matches('/').replacen("http", "ws", 1))
+            }
+            Network::Custom { ws_url, .. } => ws_url.clone(),
+        }
+    }
+
+    /// Whether actions are signed for mainnet
+    pub fn is_mainnet(&self) -> bool {
+        matches!(self, Network::Mainnet | Network::Custom { mainnet: true, .. })
+    }
+
+    /// `source` of the phantom agent L1 actions are signed as: "a" on mainnet, "b" otherwise
+    pub fn phantom_agent_source(&self) -> &'static str {
+        if self.is_mainnet() {
+            "a"
+        } else {
+            "b"
+        }
+    }
+
+    /// `hyperliquidChain` field of user-signed actions: "Mainnet" or "Testnet"
+    pub fn hyperliquid_chain(&self) -> &'static str {
+        if self.is_mainnet() {
+            "Mainnet"
+        } else {
+            "Testnet"
+        }
+    }
+
+    /// `signatureChainId` of user-signed actions: 0xa4b1 on mainnet, 0x66eee otherwise
+    pub fn signature_chain_id(&self) -> u64 {
+        if self.is_mainnet() {
+            MAINNET_SIGNATURE_CHAIN_ID
+        } else {
+            TESTNET_SIGNATURE_CHAIN_ID
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Network::Mainnet => write!(f, "mainnet"),
+            Network::Testnet => write!(f, "testnet"),
+            Network::Local { base_url } => write!(f, "local ({})", base_url),
+            Network::Custom { base_url, mainnet, .. } => {
+                write!(f, "custom {} ({})", if *mainnet { "mainnet" } else { "testnet" }, base_url)
+            }
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = HyperLiquidError;
+
+    /// Parse `mainnet`, `testnet` or `local`, ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "mainnet" => Ok(Network::Mainnet),
+            "testnet" => Ok(Network::Testnet),
+            "local" => Ok(Network::Local {
+                base_url: LOCAL_API_URL.to_string(),
+            }),
+            _ => Err(HyperLiquidError::InvalidInput(format!(
+                "Unknown network '{}': expected mainnet, testnet or local",
+                s
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        assert_eq!(Network::Mainnet.ws_url(), MAINNET_WS_URL);
+        assert_eq!(Network::Testnet.phantom_agent_source(), "b");
+        assert_eq!(Network::Mainnet.phantom_agent_source(), "a");
+        assert_eq!(Network::Mainnet.hyperliquid_chain(), "Mainnet");
+        assert_eq!(Network::Testnet.hyperliquid_chain(), "Testnet");
+        assert_eq!(Network::Mainnet.signature_chain_id(), 0xa4b1);
+        assert_eq!(Network::Testnet.signature_chain_id(), 0x66eee);
+
+        let local: Network = "LOCAL".parse().unwrap();
+        assert_eq!(local.ws_url(), "ws://localhost:3001/ws");
+        assert!(!local.is_mainnet());
+        assert_eq!((local.hyperliquid_chain(), local.signature_chain_id()), ("Testnet", 0x66eee));
+        assert!("devnet".parse::<Network>().is_err());
+    }
+
+    #[test]
+    fn test_custom_signs_as_its_mainnet_flag() {
+        let custom = |mainnet| Network::Custom {
+            base_url: "https://proxy.example.com".to_string(),
+            ws_url: "wss://proxy.example.com/ws".to_string(),
+            mainnet,
+        };
+        assert_eq!(custom(true).phantom_agent_source(), "a");
+        assert_eq!((custom(true).hyperliquid_chain(), custom(true).signature_chain_id()), ("Mainnet", 0xa4b1));
+        assert_eq!(custom(false).phantom_agent_source(), "b");
+        assert_eq!((custom(false).hyperliquid_chain(), custom(false).signature_chain_id()), ("Testnet", 0x66eee));
+    }
+
+    #[test]
+    fn test_from_base_url() {
+        assert_eq!(Network::from_base_url("https://api.hyperliquid.xyz/", MAINNET_WS_URL), Network::Mainnet);
+        assert_eq!(Network::from_base_url(TESTNET_API_URL, TESTNET_WS_URL), Network::Testnet);
+        // Unknown hosts never sign as mainnet unless declared so
+        assert!(!Network::from_base_url("https://proxy.example.com", MAINNET_WS_URL).is_mainnet());
+    }
+}
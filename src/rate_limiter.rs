@@ -0,0 +1,177 @@
+//! Client-side throttling against HyperLiquid's per-IP request weight budget.
+//!
+//! Every REST request carries a weight and an IP may spend [`DEFAULT_WEIGHT_PER_MINUTE`]
+//! per minute. A [`RateLimiter`] tracks that budget as a token bucket and makes requests
+//! wait for capacity instead of running into 429s.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Aggregated REST weight an IP may spend per minute
+pub const DEFAULT_WEIGHT_PER_MINUTE: u32 = 1200;
+
+/// Weight of an `/info` request of the given `type`, before any per-item weight
+pub fn info_request_weight(request_type: &str) -> u32 {
+    match request_type {
+        "l2Book" | "allMids" | "clearinghouseState" | "orderStatus" | "spotClearinghouseState"
+        | "exchangeStatus" => 2,
+        "userRole" => 60,
+        _ => 20,
+    }
+}
+
+/// Number of returned items that cost one additional weight, for request types whose
+/// weight grows with the size of the response
+pub fn info_items_per_weight(request_type: &str) -> Option<usize> {
+    match request_type {
+        "recentTrades" | "historicalOrders" | "userFills" | "userFillsByTime" | "fundingHistory"
+        | "userFunding" | "userNonFundingLedgerUpdates" | "twapHistory" | "userTwapSliceFills"
+        | "userTwapSliceFillsByTime" | "delegatorHistory" | "delegatorRewards" | "validatorStats" => Some(20),
+        "candleSnapshot" => Some(60),
+        _ => None,
+    }
+}
+
+/// Weight of an `/exchange` request batching `batch_len` orders, cancels or modifies
+pub fn exchange_request_weight(batch_len: usize) -> u32 {
+    1 + (batch_len / 40) as u32
+}
+
+/// Token bucket over the request weight budget
+///
+/// The bucket holds up to `capacity` weight and refills continuously at `capacity` per
+/// minute. Share one limiter between every client that uses the same IP; clients created
+/// from the same [`crate::config::HyperLiquidConfig`] share it already.
+///
+/// # Examples
+///
+/// ```rust
+/// use hyperliquid::client::HyperLiquidClient;
+/// use hyperliquid::config::HyperLiquidConfig;
+/// use hyperliquid::rate_limiter::RateLimiter;
+///
+/// let config = HyperLiquidConfig::new().with_rate_limiter(RateLimiter::default());
+/// let client = HyperLiquidClient::with_config(config).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    /// Available weight; negative after charging more than was available
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_WEIGHT_PER_MINUTE)
+    }
+}
+
+impl RateLimiter {
+    /// Limiter allowing `weight_per_minute`, starting with a full bucket
+    pub fn new(weight_per_minute: u32) -> Self {
+        Self::with_period(weight_per_minute, Duration::from_secs(60))
+    }
+
+    /// Limiter allowing `capacity` weight per `period`, starting with a full bucket
+    pub fn with_period(capacity: u32, period: Duration) -> Self {
+        let capacity = f64::from(capacity.max(1));
+        Self {
+            capacity,
+            refill_per_sec: capacity / period.as_secs_f64().max(f64::EPSILON),
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until `weight` is available, then spend it
+    ///
+    /// Weights above the bucket's capacity are treated as a full bucket.
+    pub async fn acquire(&self, weight: u32) {
+        let weight = f64::from(weight).min(self.capacity);
+        loop {
+            let wait = {
+                let mut state = self.refill();
+                if state.tokens >= weight {
+                    state.tokens -= weight;
+                    return;
+                }
+                Duration::from_secs_f64((weight - state.tokens) / self.refill_per_sec)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Spend `weight` without waiting, e.g. for per-item weight known only after a response
+    ///
+    /// The bucket may go into debt, which later calls to [`RateLimiter::acquire`] wait out.
+    pub fn charge(&self, weight: u32) {
+        self.refill().tokens -= f64::from(weight);
+    }
+
+    /// Weight currently available
+    pub fn available(&self) -> f64 {
+        self.refill().tokens
+    }
+
+    fn refill(&self) -> std::sync::MutexGuard<'_, BucketState> {
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        state.refilled_at = now;
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_weights() {
+        assert_eq!(info_request_weight("l2Book"), 2);
+        assert_eq!(info_request_weight("allMids"), 2);
+        assert_eq!(info_request_weight("userRole"), 60);
+        assert_eq!(info_request_weight("meta"), 20);
+        assert_eq!(info_items_per_weight("userFills"), Some(20));
+        assert_eq!(info_items_per_weight("candleSnapshot"), Some(60));
+        assert_eq!(info_items_per_weight("l2Book"), None);
+        assert_eq!(exchange_request_weight(0), 1);
+        assert_eq!(exchange_request_weight(79), 2);
+        assert_eq!(exchange_request_weight(80), 3);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_refill() {
+        let limiter = RateLimiter::with_period(10, Duration::from_millis(200));
+
+        let start = Instant::now();
+        limiter.acquire(10).await;
+        assert!(start.elapsed() < Duration::from_millis(50), "A full bucket should not wait");
+
+        limiter.acquire(5).await;
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(90), "Should wait for half the bucket to refill");
+        assert!(elapsed < Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn test_charge_goes_into_debt() {
+        let limiter = RateLimiter::with_period(10, Duration::from_millis(200));
+        limiter.charge(15);
+        assert!(limiter.available() < -4.0);
+
+        let start = Instant::now();
+        limiter.acquire(1).await;
+        assert!(start.elapsed() >= Duration::from_millis(100), "Debt should be paid back first");
+    }
+}
@@ -0,0 +1,264 @@
+//! Record-and-replay of `/info` traffic as JSON fixtures.
+//!
+//! A [`RecordingTransport`] forwards requests to another transport and writes each `/info`
+//! request with its response to a fixture file. A [`ReplayTransport`] later serves those
+//! fixtures back without touching the network, so a session captured once can back
+//! regression tests indefinitely.
+//!
+//! Fixtures are named `<type>-<hash>.json` after the request type and a hash of the request
+//! body, so the same request always maps to the same file. `/exchange` requests carry
+//! nonces and signatures and are neither recorded nor replayed.
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::errors::HyperLiquidError;
+use crate::exchange::signing::keccak256;
+use crate::transport::{Transport, TransportFuture, TransportRequest, TransportResponse};
+
+/// One recorded request and its response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    pub request: FixtureRequest,
+    pub response: FixtureResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixtureRequest {
+    pub path: String,
+    pub body: Value,
+}
+
+/// Recorded response; the body is kept as JSON when it parses, as text otherwise
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixtureResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl FixtureResponse {
+    fn from_response(response: &TransportResponse) -> Self {
+        let headers = response
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        match serde_json::from_str(&response.body) {
+            Ok(json) => Self { status: response.status, headers, json: Some(json), text: None },
+            Err(_) => Self { status: response.status, headers, json: None, text: Some(response.body.clone()) },
+        }
+    }
+
+    fn to_response(&self) -> TransportResponse {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) = (HeaderName::try_from(name.as_str()), HeaderValue::from_str(value)) {
+                headers.insert(name, value);
+            }
+        }
+        let body = match (&self.json, &self.text) {
+            (Some(json), _) => json.to_string(),
+            (None, Some(text)) => text.clone(),
+            (None, None) => String::new(),
+        };
+        TransportResponse { status: self.status, headers, body }
+    }
+}
+
+/// File name of the fixture for a request, or `None` for requests that are not recorded
+pub fn fixture_name(request: &TransportRequest) -> Option<String> {
+    if request.path() != "/info" {
+        return None;
+    }
+    // Re-serializing sorts the keys, so field order does not change the name
+    let body: Value = serde_json::from_str(&request.body).ok()?;
+    let request_type = body["type"].as_str().unwrap_or("unknown");
+    let hash = keccak256(body.to_string().as_bytes());
+    Some(format!("{}-{}.json", request_type, hex::encode(&hash[..8])))
+}
+
+/// Transport writing every `/info` exchange it forwards to a fixture directory
+///
+/// Recording never changes the outcome of a request: if a fixture cannot be written, the
+/// response is still returned and the error is passed to the hook set with
+/// [`RecordingTransport::with_write_error_hook`] (and logged with the `tracing` feature).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use hyperliquid::client::HyperLiquidClient;
+/// use hyperliquid::config::HyperLiquidConfig;
+///
+/// // Capture a session against the real API...
+/// let config = HyperLiquidConfig::new().with_record_dir("tests/fixtures");
+/// let client = HyperLiquidClient::with_config(config).unwrap();
+/// ```
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    dir: PathBuf,
+    on_write_error: Option<WriteErrorHook>,
+}
+
+/// Called with the fixture path and the error when a fixture cannot be written
+pub type WriteErrorHook = Arc<dyn Fn(&Path, &std::io::Error) + Send + Sync>;
+
+impl RecordingTransport {
+    pub fn new(inner: impl Transport + 'static, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            dir: dir.into(),
+            on_write_error: None,
+        }
+    }
+
+    /// Report fixtures that could not be written, e.g. to fail a recording run afterwards
+    pub fn with_write_error_hook(mut self, hook: impl Fn(&Path, &std::io::Error) + Send + Sync + 'static) -> Self {
+        self.on_write_error = Some(Arc::new(hook));
+        self
+    }
+}
+
+impl std::fmt::Debug for RecordingTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecordingTransport")
+            .field("inner", &self.inner)
+            .field("dir", &self.dir)
+            .field("on_write_error", &self.on_write_error.is_some())
+            .finish()
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response = self.inner.send(request).await?;
+
+            if let Some(name) = fixture_name(request) {
+                let path = self.dir.join(name);
+                if let Err(error) = write_fixture(&path, request, &response).await {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(path = %path.display(), error = %error, "failed to write fixture");
+                    if let Some(hook) = &self.on_write_error {
+                        hook(&path, &error);
+                    }
+                }
+            }
+            Ok(response)
+        })
+    }
+}
+
+async fn write_fixture(path: &Path, request: &TransportRequest, response: &TransportResponse) -> std::io::Result<()> {
+    let fixture = Fixture {
+        request: FixtureRequest {
+            path: request.path().to_string(),
+            body: serde_json::from_str(&request.body)?,
+        },
+        response: FixtureResponse::from_response(response),
+    };
+    if let Some(dir) = path.parent() {
+        tokio::fs::create_dir_all(dir).await?;
+    }
+    tokio::fs::write(path, serde_json::to_vec_pretty(&fixture)?).await
+}
+
+/// Transport serving fixtures written by a [`RecordingTransport`]
+///
+/// Requests without a fixture get a 404 naming the missing file, which surfaces as
+/// [`HyperLiquidError::ApiError`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use hyperliquid::client::HyperLiquidClient;
+/// use hyperliquid::recording::ReplayTransport;
+///
+/// # async fn example() -> hyperliquid::errors::Result<()> {
+/// // ...and replay it in CI
+/// let client = HyperLiquidClient::new().with_transport(ReplayTransport::new("tests/fixtures"));
+/// let fees = client.get_user_fees("0x1234567890123456789012345678901234567890").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    dir: PathBuf,
+}
+
+impl ReplayTransport {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let Some(name) = fixture_name(request) else {
+                return Ok(TransportResponse::new(404, format!("{} requests are not replayed", request.path())));
+            };
+            let path = self.dir.join(&name);
+            let Ok(contents) = tokio::fs::read(&path).await else {
+                return Ok(TransportResponse::new(404, format!("No fixture recorded at {}", path.display())));
+            };
+
+            let fixture: Fixture = serde_json::from_slice(&contents)
+                .map_err(|e| HyperLiquidError::InvalidInput(format!("Invalid fixture {}: {}", path.display(), e)))?;
+            Ok(fixture.response.to_response())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MemoryTransport;
+
+    #[test]
+    fn test_fixture_name_ignores_key_order() {
+        let a = TransportRequest::new("http://x/info", r#"{"type":"l2Book","coin":"BTC"}"#);
+        let b = TransportRequest::new("http://x/info", r#"{"coin":"BTC","type":"l2Book"}"#);
+        let c = TransportRequest::new("http://x/info", r#"{"type":"l2Book","coin":"ETH"}"#);
+
+        assert!(fixture_name(&a).unwrap().starts_with("l2Book-"));
+        assert_eq!(fixture_name(&a), fixture_name(&b));
+        assert_ne!(fixture_name(&a), fixture_name(&c));
+        assert_eq!(fixture_name(&TransportRequest::new("http://x/exchange", "{}")), None);
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let dir = std::env::temp_dir().join(format!("hyperliquid-fixtures-{}", std::process::id()));
+        let recorder = RecordingTransport::new(
+            MemoryTransport::new()
+                .with_response("allMids", serde_json::json!({"BTC": "1"}))
+                .with_status("meta", 500, "boom"),
+            &dir,
+        );
+
+        let mids = TransportRequest::new("http://x/info", r#"{"type":"allMids"}"#);
+        let meta = TransportRequest::new("http://x/info", r#"{"type":"meta"}"#);
+        recorder.send(&mids).await.unwrap();
+        recorder.send(&meta).await.unwrap();
+
+        let replay = ReplayTransport::new(&dir);
+        let response = replay.send(&mids).await.unwrap();
+        assert_eq!((response.status, response.body.as_str()), (200, r#"{"BTC":"1"}"#));
+        let response = replay.send(&meta).await.unwrap();
+        assert_eq!((response.status, response.body.as_str()), (500, "boom"));
+
+        let missing = replay.send(&TransportRequest::new("http://x/info", r#"{"type":"userRole"}"#)).await.unwrap();
+        assert_eq!(missing.status, 404);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
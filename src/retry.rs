@@ -0,0 +1,245 @@
+//! Backoff and retryability rules for HTTP requests.
+//!
+//! A [`RetryPolicy`] is part of [`crate::config::HyperLiquidConfig`] and decides which
+//! failures are retried and how long to wait in between. The number of retries itself is
+//! [`crate::config::HyperLiquidConfig::max_retries`].
+
+use rand::Rng;
+use std::collections::BTreeSet;
+use std::time::{Duration, SystemTime};
+
+/// Randomization applied to the computed backoff delay
+///
+/// Jitter spreads out the retries of many clients that failed at the same moment, so they
+/// don't hit the API again in lockstep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Jitter {
+    /// Wait exactly the computed delay
+    #[default]
+    None,
+    /// Wait a random delay between zero and the computed delay
+    Full,
+    /// Wait half of the computed delay plus a random delay up to the other half
+    Equal,
+}
+
+/// How failed requests are retried
+///
+/// The delay before retry `n` (starting at 0) is `base_delay * multiplier^n`, capped at
+/// `max_delay` and then jittered. A `Retry-After` header on the response takes precedence
+/// over the computed delay and is not jittered, so the client never retries earlier than the
+/// server asked: when it asks for longer than `max_delay`, or than is left before the
+/// deadline, the request is not retried and fails with the response's error, e.g.
+/// [`crate::errors::HyperLiquidError::RateLimitExceeded`] for a 429.
+///
+/// # Examples
+///
+/// ```rust
+/// use hyperliquid::config::HyperLiquidConfig;
+/// use hyperliquid::retry::{Jitter, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .with_backoff(Duration::from_millis(200), Duration::from_secs(5))
+///     .with_jitter(Jitter::Full)
+///     .with_deadline(Some(Duration::from_secs(20)))
+///     .with_retryable_status(501, false);
+///
+/// let config = HyperLiquidConfig::new().with_retry_policy(policy);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound for the computed delay
+    pub max_delay: Duration,
+    /// Factor the delay grows by on every further retry
+    pub multiplier: f64,
+    pub jitter: Jitter,
+    /// HTTP statuses that are retried; any other non-success status fails immediately
+    pub retryable_statuses: BTreeSet<u16>,
+    /// Retry connection failures and timeouts
+    pub retry_transport_errors: bool,
+    /// Wait as long as the `Retry-After` header asks for, in seconds or as an HTTP date, and
+    /// give up if that is longer than `max_delay`
+    pub respect_retry_after: bool,
+    /// Give up once this much time has passed since the first attempt, including waits
+    pub deadline: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: Jitter::None,
+            retryable_statuses: std::iter::once(429).chain(500..=599).collect(),
+            retry_transport_errors: true,
+            respect_retry_after: true,
+            deadline: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Mark a status as retryable or not
+    pub fn with_retryable_status(mut self, status: u16, retryable: bool) -> Self {
+        if retryable {
+            self.retryable_statuses.insert(status);
+        } else {
+            self.retryable_statuses.remove(&status);
+        }
+        self
+    }
+
+    pub fn with_retry_transport_errors(mut self, retry: bool) -> Self {
+        self.retry_transport_errors = retry;
+        self
+    }
+
+    pub fn with_respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    pub fn with_deadline(mut self, deadline: Option<Duration>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Whether a response with this status should be retried
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// Computed delay before retry `attempt` (starting at 0), without jitter
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.max(1.0).powi(attempt.min(i32::MAX as u32) as i32);
+        let delay = self.base_delay.as_secs_f64() * factor;
+        if delay.is_finite() && delay < self.max_delay.as_secs_f64() {
+            Duration::from_secs_f64(delay)
+        } else {
+            self.max_delay
+        }
+    }
+
+    /// Delay before retry `attempt`, given the response's `Retry-After` header if any
+    ///
+    /// Returns `None` if `Retry-After` asks for longer than `max_delay`, in which case the
+    /// request should not be retried.
+    pub fn delay(&self, attempt: u32, retry_after: Option<&str>) -> Option<Duration> {
+        if self.respect_retry_after {
+            if let Some(delay) = retry_after.and_then(parse_retry_after) {
+                return (delay <= self.max_delay).then_some(delay);
+            }
+        }
+
+        let delay = self.backoff(attempt);
+        Some(match self.jitter {
+            Jitter::None => delay,
+            Jitter::Full => delay.mul_f64(rand::thread_rng().gen::<f64>()),
+            Jitter::Equal => delay / 2 + (delay / 2).mul_f64(rand::thread_rng().gen::<f64>()),
+        })
+    }
+}
+
+/// Parse a `Retry-After` value, either delay seconds or an HTTP date
+///
+/// Dates in the past yield a zero delay; malformed values yield `None`.
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(SystemTime::now()).unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        let policy = RetryPolicy::new()
+            .with_backoff(Duration::from_millis(100), Duration::from_millis(1000))
+            .with_multiplier(3.0);
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(300));
+        assert_eq!(policy.backoff(2), Duration::from_millis(900));
+        assert_eq!(policy.backoff(3), Duration::from_millis(1000));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_jitter_bounds() {
+        let policy = RetryPolicy::new().with_backoff(Duration::from_secs(8), Duration::from_secs(8));
+
+        for _ in 0..100 {
+            let full = policy.clone().with_jitter(Jitter::Full).delay(0, None).unwrap();
+            assert!(full <= Duration::from_secs(8));
+
+            let equal = policy.clone().with_jitter(Jitter::Equal).delay(0, None).unwrap();
+            assert!(equal >= Duration::from_secs(4) && equal <= Duration::from_secs(8));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_takes_precedence() {
+        let policy = RetryPolicy::new();
+        assert_eq!(policy.delay(5, Some("2")), Some(Duration::from_secs(2)));
+        assert_eq!(policy.delay(0, Some("invalid")), Some(Duration::from_secs(1)));
+        assert_eq!(policy.delay(0, Some("30")), Some(policy.max_delay));
+        assert_eq!(policy.delay(0, Some("86400")), None);
+
+        let policy = policy.with_respect_retry_after(false);
+        assert_eq!(policy.delay(0, Some("10")), Some(Duration::from_secs(1)));
+        assert_eq!(policy.delay(0, Some("86400")), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after(" 3 "), Some(Duration::from_secs(3)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("-1"), None);
+        assert_eq!(parse_retry_after("soon"), None);
+
+        let future = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(120));
+        let delay = parse_retry_after(&future).unwrap();
+        assert!(delay > Duration::from_secs(110) && delay <= Duration::from_secs(120));
+    }
+
+    #[test]
+    fn test_retryable_statuses() {
+        let policy = RetryPolicy::new().with_retryable_status(501, false).with_retryable_status(408, true);
+
+        assert!(policy.is_retryable_status(429));
+        assert!(policy.is_retryable_status(503));
+        assert!(policy.is_retryable_status(408));
+        assert!(!policy.is_retryable_status(501));
+        assert!(!policy.is_retryable_status(404));
+    }
+}
@@ -0,0 +1,238 @@
+//! HTTP transport behind [`crate::client::HyperLiquidClient`].
+//!
+//! Every REST request goes through a [`Transport`], which sends one JSON body and returns
+//! the raw response. Retries, rate limiting and decoding stay in the client, so a transport
+//! only has to move bytes. [`ReqwestTransport`] is used by default; [`MemoryTransport`]
+//! answers from canned JSON for unit tests that should not open sockets.
+
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use crate::errors::{HyperLiquidError, Result};
+
+/// Future returned by [`Transport::send`]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<TransportResponse>> + Send + 'a>>;
+
+/// A JSON `POST` to the API
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    /// Full URL, e.g. `https://api.hyperliquid.xyz/info`
+    pub url: String,
+    /// Serialized JSON body
+    pub body: String,
+    /// Headers sent in addition to `Content-Type`
+    pub headers: HeaderMap,
+}
+
+impl TransportRequest {
+    pub fn new(url: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            body: body.into(),
+            headers: HeaderMap::new(),
+        }
+    }
+
+    /// Path of the URL without the base, e.g. `/info`
+    pub fn path(&self) -> &str {
+        let without_scheme = self.url.split_once("://").map_or(self.url.as_str(), |(_, rest)| rest);
+        without_scheme.find('/').map_or("/", |index| &without_scheme[index..])
+    }
+
+    /// `type` of an `/info` body, or the action's `type` of an `/exchange` body
+    pub fn request_type(&self) -> Option<String> {
+        let body: Value = serde_json::from_str(&self.body).ok()?;
+        body["type"]
+            .as_str()
+            .or_else(|| body["action"]["type"].as_str())
+            .map(str::to_string)
+    }
+}
+
+/// A response with its body read in full
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl TransportResponse {
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+}
+
+/// Sends requests on behalf of the client
+///
+/// Implementations report failures to reach the API as [`HyperLiquidError::Timeout`],
+/// [`HyperLiquidError::ConnectionError`] or [`HyperLiquidError::HttpError`]; the client
+/// retries those according to its [`crate::retry::RetryPolicy`]. `ConnectionError` must only
+/// be used when the request was not sent, e.g. the connection could not be opened, since it
+/// is the one error signed `/exchange` posts are retried on. Non-success statuses are
+/// returned as responses, not errors.
+pub trait Transport: Send + Sync + fmt::Debug {
+    fn send<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a>;
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a> {
+        (**self).send(request)
+    }
+}
+
+/// Default transport over a pooled [`reqwest::Client`]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .post(&request.url)
+                .header("Content-Type", "application/json")
+                .headers(request.headers.clone())
+                .body(request.body.clone())
+                .send()
+                .await
+                .map_err(transport_error)?;
+
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.text().await.map_err(transport_error)?;
+            Ok(TransportResponse { status, headers, body })
+        })
+    }
+}
+
+/// Map a reqwest failure onto the errors a [`Transport`] reports
+fn transport_error(error: reqwest::Error) -> HyperLiquidError {
+    if error.is_timeout() {
+        HyperLiquidError::Timeout
+    } else if error.is_connect() {
+        HyperLiquidError::ConnectionError {
+            message: error.to_string(),
+            retries: 0,
+        }
+    } else {
+        HyperLiquidError::HttpError(error)
+    }
+}
+
+/// Transport answering from canned responses keyed by request type
+///
+/// `/info` requests are matched on their `type`, `/exchange` requests on their action's
+/// `type`. Requests without a canned response get a 422, like the API does for unknown
+/// request types. Every request is recorded for later inspection; keep an `Arc` of the
+/// transport to read them after handing it to a client.
+///
+/// # Examples
+///
+/// ```rust
+/// use hyperliquid::client::HyperLiquidClient;
+/// use hyperliquid::transport::MemoryTransport;
+/// use serde_json::json;
+///
+/// # async fn example() -> hyperliquid::errors::Result<()> {
+/// let transport = MemoryTransport::new()
+///     .with_response("allMids", json!({"BTC": "50000.0"}))
+///     .with_status("userRole", 500, "boom");
+/// let client = HyperLiquidClient::new().with_transport(transport);
+///
+/// let mids = client.get_all_mids().await?;
+/// assert_eq!(mids.prices["BTC"].0.to_string(), "50000.0");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryTransport {
+    responses: HashMap<String, TransportResponse>,
+    requests: Mutex<Vec<TransportRequest>>,
+}
+
+impl MemoryTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer requests of `request_type` with `body` and a 200
+    pub fn with_response(self, request_type: impl Into<String>, body: Value) -> Self {
+        self.with_status(request_type, 200, body.to_string())
+    }
+
+    /// Answer requests of `request_type` with any status and raw body
+    pub fn with_status(mut self, request_type: impl Into<String>, status: u16, body: impl Into<String>) -> Self {
+        self.responses.insert(request_type.into(), TransportResponse::new(status, body));
+        self
+    }
+
+    /// Requests sent so far, oldest first
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.requests.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn send<'a>(&'a self, request: &'a TransportRequest) -> TransportFuture<'a> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(request.clone());
+
+        let request_type = request.request_type().unwrap_or_default();
+        let response = self.responses.get(&request_type).cloned().unwrap_or_else(|| {
+            TransportResponse::new(422, format!("No canned response for request type '{}'", request_type))
+        });
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(url: &str, body: Value) -> TransportRequest {
+        TransportRequest::new(url, body.to_string())
+    }
+
+    #[test]
+    fn test_request_path_and_type() {
+        let info = request("http://127.0.0.1:8080/info", serde_json::json!({"type": "l2Book", "coin": "BTC"}));
+        assert_eq!(info.path(), "/info");
+        assert_eq!(info.request_type().as_deref(), Some("l2Book"));
+
+        let exchange = request("https://api.hyperliquid.xyz/exchange", serde_json::json!({"action": {"type": "order"}}));
+        assert_eq!(exchange.path(), "/exchange");
+        assert_eq!(exchange.request_type().as_deref(), Some("order"));
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_records_and_answers() {
+        let transport = MemoryTransport::new().with_response("allMids", serde_json::json!({"BTC": "1"}));
+
+        let response = transport.send(&request("http://x/info", serde_json::json!({"type": "allMids"}))).await.unwrap();
+        assert_eq!((response.status, response.body.as_str()), (200, r#"{"BTC":"1"}"#));
+
+        let response = transport.send(&request("http://x/info", serde_json::json!({"type": "meta"}))).await.unwrap();
+        assert_eq!(response.status, 422);
+        assert_eq!(transport.requests().len(), 2);
+    }
+}
@@ -0,0 +1,693 @@
+//! WebSocket client for real-time market and user data.
+//!
+//! A background task owns the socket: it forwards outgoing requests written by
+//! [`WsClient`] and parses incoming frames into [`WsMessage`]s that are delivered through
+//! [`WsClient::next_message`].
+//!
+//! The task also keeps the connection alive: it sends `ping` heartbeats, treats a socket
+//! that stays silent for too long as dead, and reconnects with exponential backoff,
+//! re-issuing every active subscription.
+//!
+//! Info and signed exchange requests can also ride the socket as `post` requests; responses
+//! are correlated with their request by id.
+//!
+//! [`WsClient::split`] separates the client into a cloneable [`WsSender`] for subscriptions
+//! and posts and a [`WsReceiver`] stream of messages, so posts can be awaited while another
+//! task reads the feed. Incoming messages are buffered up to `config.ws_message_buffer`;
+//! when the receiver falls behind, further market data is dropped and reported with
+//! [`WsMessage::Lagged`] instead of growing the buffer without bound. [`WsMessage::Gap`] and
+//! [`WsMessage::Reconnected`] are never dropped, only delayed until there is room.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::action_budget::ActionBudget;
+use crate::config::HyperLiquidConfig;
+use crate::errors::{HyperLiquidError, Result};
+use crate::exchange::action::{ExchangeAction, ExchangeRequest, ExchangeResponse};
+use crate::ws::connect::{open, WsStream};
+use crate::ws::message::{WsMessage, WsPostPayload};
+use crate::ws::subscription::{Subscription, SubscriptionRequest};
+
+/// Client for the HyperLiquid WebSocket API
+///
+/// The connection is closed once the client, or both halves returned by
+/// [`WsClient::split`], are dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use hyperliquid::config::MAINNET_WS_URL;
+/// use hyperliquid::ws::client::WsClient;
+/// use hyperliquid::ws::message::WsMessage;
+/// use hyperliquid::ws::subscription::Subscription;
+///
+/// #[tokio::main]
+/// async fn main() -> hyperliquid::errors::Result<()> {
+///     let mut ws = WsClient::connect(MAINNET_WS_URL).await?;
+///     ws.subscribe(Subscription::l2_book("BTC"))?;
+///
+///     while let Some(message) = ws.next_message().await {
+///         if let WsMessage::L2Book(book) = message {
+///             println!("{} bids, {} asks", book.levels[0].len(), book.levels[1].len());
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct WsClient {
+    // Dropped first, so the background task sees the whole client gone once the sender goes
+    receiver: WsReceiver,
+    sender: WsSender,
+}
+
+/// Sending half of a [`WsClient`]: subscriptions and `post` requests
+///
+/// Cheap to clone; clones share the connection.
+#[derive(Clone)]
+pub struct WsSender {
+    outgoing: mpsc::UnboundedSender<Outgoing>,
+    subscriptions: Arc<Mutex<HashSet<Subscription>>>,
+    pending_posts: PendingPosts,
+    next_post_id: Arc<AtomicU64>,
+    action_budget: Option<Arc<ActionBudget>>,
+    post_timeout: Duration,
+}
+
+/// Receiving half of a [`WsClient`]: a stream of incoming messages
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use hyperliquid::config::MAINNET_WS_URL;
+/// use hyperliquid::info::l2_book::{L2BookRequest, L2BookResponse};
+/// use hyperliquid::ws::client::WsClient;
+/// use hyperliquid::ws::subscription::Subscription;
+///
+/// # async fn example() -> hyperliquid::errors::Result<()> {
+/// let (sender, mut receiver) = WsClient::connect(MAINNET_WS_URL).await?.split();
+/// sender.subscribe(Subscription::trades("BTC"))?;
+///
+/// tokio::spawn(async move {
+///     while let Some(message) = receiver.next().await {
+///         println!("{:?}", message);
+///     }
+/// });
+///
+/// let book: L2BookResponse = sender.post_info(&L2BookRequest::new("BTC")).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct WsReceiver {
+    incoming: mpsc::Receiver<WsMessage>,
+}
+
+/// Senders for `post` requests awaiting a response, keyed by request id; `None` is sent
+/// for a request dropped before it was written to the socket
+type PendingPosts = Arc<Mutex<HashMap<u64, oneshot::Sender<Option<WsPostPayload>>>>>;
+
+/// A failed `post` request, and whether it may have reached the server
+struct PostFailure {
+    error: HyperLiquidError,
+    sent: bool,
+}
+
+impl From<HyperLiquidError> for PostFailure {
+    fn from(error: HyperLiquidError) -> Self {
+        PostFailure { error, sent: true }
+    }
+}
+
+impl WsClient {
+    /// Connect to a WebSocket endpoint, e.g. [`crate::config::MAINNET_WS_URL`], using the
+    /// default heartbeat and reconnect settings
+    pub async fn connect(url: &str) -> Result<Self> {
+        Self::connect_with_config(HyperLiquidConfig::default().with_ws_url(url)).await
+    }
+
+    /// Connect to `config.ws_url` with the configured heartbeat and reconnect settings
+    ///
+    /// The socket is opened with the same `proxy`, `root_certificates`, `local_address` and
+    /// `resolve_overrides` as REST requests, on the first attempt and every reconnect.
+    ///
+    /// Only the initial connection attempt is reported as an error; later disconnects are
+    /// retried in the background and surfaced as [`WsMessage::Gap`] and
+    /// [`WsMessage::Reconnected`].
+    pub async fn connect_with_config(config: HyperLiquidConfig) -> Result<Self> {
+        let stream = open(&config).await?;
+
+        let (outgoing_tx, outgoing_rx) = mpsc::unbounded_channel();
+        let (incoming_tx, incoming_rx) = mpsc::channel(config.ws_message_buffer.max(1));
+        let subscriptions = Arc::new(Mutex::new(HashSet::new()));
+        let pending_posts = PendingPosts::default();
+        let post_timeout = config.ws_post_timeout;
+
+        let connection = Connection {
+            config,
+            outgoing: outgoing_rx,
+            senders_gone: false,
+            incoming: incoming_tx,
+            undelivered: VecDeque::new(),
+            subscriptions: subscriptions.clone(),
+            pending_posts: pending_posts.clone(),
+        };
+        tokio::spawn(connection.run(stream));
+
+        Ok(Self {
+            sender: WsSender {
+                outgoing: outgoing_tx,
+                subscriptions,
+                pending_posts,
+                next_post_id: Arc::new(AtomicU64::new(1)),
+                action_budget: None,
+                post_timeout,
+            },
+            receiver: WsReceiver { incoming: incoming_rx },
+        })
+    }
+
+    /// Count actions posted with [`WsSender::post_action`] against an address's action
+    /// budget, e.g. the one shared with [`HyperLiquidClient::with_action_budget`](crate::client::HyperLiquidClient::with_action_budget)
+    pub fn with_action_budget(mut self, action_budget: Arc<ActionBudget>) -> Self {
+        self.sender.action_budget = Some(action_budget);
+        self
+    }
+
+    /// Separate the sending and receiving halves, e.g. to await posts while another task
+    /// reads messages
+    pub fn split(self) -> (WsSender, WsReceiver) {
+        (self.sender, self.receiver)
+    }
+
+    /// A handle sharing this client's connection for subscriptions and posts
+    pub fn sender(&self) -> WsSender {
+        self.sender.clone()
+    }
+
+    /// See [`WsSender::subscribe`]
+    pub fn subscribe(&self, subscription: Subscription) -> Result<()> {
+        self.sender.subscribe(subscription)
+    }
+
+    /// See [`WsSender::unsubscribe`]
+    pub fn unsubscribe(&self, subscription: &Subscription) -> Result<()> {
+        self.sender.unsubscribe(subscription)
+    }
+
+    /// Currently active subscriptions
+    pub fn subscriptions(&self) -> Vec<Subscription> {
+        self.sender.subscriptions()
+    }
+
+    /// See [`WsReceiver::next_message`]
+    pub async fn next_message(&mut self) -> Option<WsMessage> {
+        self.receiver.next_message().await
+    }
+
+    /// See [`WsSender::post_info`]
+    pub async fn post_info<B, T>(&self, body: &B) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        self.sender.post_info(body).await
+    }
+
+    /// See [`WsSender::post_action`]
+    pub async fn post_action<A, T>(&self, request: &ExchangeRequest<A>) -> Result<T>
+    where
+        A: ExchangeAction,
+        T: DeserializeOwned,
+    {
+        self.sender.post_action(request).await
+    }
+}
+
+impl WsSender {
+    /// Subscribe to a data feed
+    ///
+    /// The server acknowledges with a [`WsMessage::SubscriptionResponse`]; data messages
+    /// follow on the subscription's channel.
+    pub fn subscribe(&self, subscription: Subscription) -> Result<()> {
+        subscription.validate()?;
+        self.send_subscription_request("subscribe", &subscription)?;
+        self.subscriptions.lock().unwrap().insert(subscription);
+        Ok(())
+    }
+
+    /// Unsubscribe from a data feed
+    pub fn unsubscribe(&self, subscription: &Subscription) -> Result<()> {
+        self.send_subscription_request("unsubscribe", subscription)?;
+        self.subscriptions.lock().unwrap().remove(subscription);
+        Ok(())
+    }
+
+    /// Currently active subscriptions
+    pub fn subscriptions(&self) -> Vec<Subscription> {
+        self.subscriptions.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Send an `/info` request over the socket and wait for its response
+    ///
+    /// `body` is any info request body, e.g.
+    /// [`L2BookRequest`](crate::info::l2_book::L2BookRequest) or
+    /// [`CandleSnapshotRequest`](crate::info::candle_snapshot::CandleSnapshotRequest); the
+    /// response decodes into the same type the REST endpoint returns.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`HyperLiquidError::Timeout`] if no response arrives within
+    /// `config.ws_post_timeout`, and with [`HyperLiquidError::WebSocketError`] if the
+    /// connection drops before the response arrives.
+    pub async fn post_info<B, T>(&self, body: &B) -> Result<T>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        match self.post("info", body).await.map_err(|failure| failure.error)? {
+            WsPostPayload::Info(info) => Ok(serde_json::from_value(info.data)?),
+            other => Err(HyperLiquidError::WebSocketError(format!("Expected an info response, got {:?}", other))),
+        }
+    }
+
+    /// Send a signed exchange action over the socket and wait for its response
+    ///
+    /// Sign the action with [`HyperLiquidClient::sign_action`](crate::client::HyperLiquidClient::sign_action).
+    /// Returns the `response` payload of a successful reply; an `"err"` status is surfaced as
+    /// [`HyperLiquidError::ExchangeError`]. Errors are otherwise the same as
+    /// [`WsSender::post_info`]; after a dropped connection the action may or may not have
+    /// been executed.
+    ///
+    /// With an action budget, the action is counted against it before it is sent and
+    /// refused with [`HyperLiquidError::ActionBudgetExhausted`] if it would exceed the cap;
+    /// the count is given back if the request was never written to the socket.
+    pub async fn post_action<A, T>(&self, request: &ExchangeRequest<A>) -> Result<T>
+    where
+        A: ExchangeAction,
+        T: DeserializeOwned,
+    {
+        let requests = request.action.request_count();
+        if let Some(budget) = &self.action_budget {
+            budget.spend(requests)?;
+        }
+
+        let payload = match self.post("action", request).await {
+            Ok(payload) => payload,
+            Err(PostFailure { error, sent }) => {
+                if let (Some(budget), false) = (&self.action_budget, sent) {
+                    budget.refund(requests);
+                }
+                return Err(error);
+            }
+        };
+
+        match payload {
+            WsPostPayload::Action(payload) => {
+                match serde_json::from_value(payload)? {
+                    ExchangeResponse::Ok(data) => Ok(data),
+                    ExchangeResponse::Err(message) => Err(HyperLiquidError::ExchangeError(message)),
+                }
+            }
+            other => Err(HyperLiquidError::WebSocketError(format!("Expected an action response, got {:?}", other))),
+        }
+    }
+
+    async fn post<P: Serialize>(&self, request_type: &'static str, payload: &P) -> std::result::Result<WsPostPayload, PostFailure> {
+        let id = self.next_post_id.fetch_add(1, Ordering::Relaxed);
+        let text = serde_json::to_string(&PostRequest {
+            method: "post",
+            id,
+            request: PostRequestBody { request_type, payload },
+        })
+        .map_err(|e| PostFailure { error: e.into(), sent: false })?;
+
+        let (response_tx, response_rx) = oneshot::channel();
+        self.pending_posts.lock().unwrap().insert(id, response_tx);
+
+        if let Err(error) = self.send(Outgoing::Post { id, text }) {
+            self.pending_posts.lock().unwrap().remove(&id);
+            return Err(PostFailure { error, sent: false });
+        }
+
+        match tokio::time::timeout(self.post_timeout, response_rx).await {
+            Ok(Ok(Some(WsPostPayload::Error(message)))) => {
+                Err(HyperLiquidError::WebSocketError(format!("Post request {} failed: {}", id, message)).into())
+            }
+            Ok(Ok(Some(payload))) => Ok(payload),
+            Ok(Ok(None)) => Err(PostFailure {
+                error: HyperLiquidError::WebSocketError(format!(
+                    "Connection lost before post request {} was sent",
+                    id
+                )),
+                sent: false,
+            }),
+            Ok(Err(_)) => Err(HyperLiquidError::WebSocketError(format!(
+                "Connection lost before post request {} was answered",
+                id
+            ))
+            .into()),
+            Err(_) => {
+                self.pending_posts.lock().unwrap().remove(&id);
+                Err(HyperLiquidError::Timeout.into())
+            }
+        }
+    }
+
+    fn send_subscription_request(&self, method: &'static str, subscription: &Subscription) -> Result<()> {
+        let request = serde_json::to_string(&SubscriptionRequest { method, subscription })?;
+        self.send(Outgoing::Subscription(request))
+    }
+
+    fn send(&self, outgoing: Outgoing) -> Result<()> {
+        self.outgoing
+            .send(outgoing)
+            .map_err(|_| HyperLiquidError::WebSocketError("Connection is closed".to_string()))
+    }
+}
+
+impl WsReceiver {
+    /// Wait for the next message
+    ///
+    /// Returns `None` once the connection has been closed and could not be re-established.
+    pub async fn next_message(&mut self) -> Option<WsMessage> {
+        self.incoming.recv().await
+    }
+}
+
+impl Stream for WsReceiver {
+    type Item = WsMessage;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<WsMessage>> {
+        self.incoming.poll_recv(cx)
+    }
+}
+
+const PING_REQUEST: &str = r#"{"method":"ping"}"#;
+
+/// Outgoing `post` request: `{"method": "post", "id": 1, "request": {"type": "info", "payload": ...}}`
+#[derive(Serialize)]
+struct PostRequest<'a, P> {
+    method: &'static str,
+    id: u64,
+    request: PostRequestBody<'a, P>,
+}
+
+#[derive(Serialize)]
+struct PostRequestBody<'a, P> {
+    #[serde(rename = "type")]
+    request_type: &'static str,
+    payload: &'a P,
+}
+
+/// A frame queued for the socket
+enum Outgoing {
+    /// Subscription change; superseded by the replay after a reconnect
+    Subscription(String),
+    /// `post` request whose response is awaited under `id`
+    Post { id: u64, text: String },
+}
+
+/// Why a connection stopped being pumped
+enum Exit {
+    /// Both halves of the [`WsClient`] were dropped; shut down for good
+    ClientGone,
+    /// The connection was lost and should be re-established
+    Lost(String),
+}
+
+/// Background side of a [`WsClient`], owning the socket across reconnects
+struct Connection {
+    config: HyperLiquidConfig,
+    outgoing: mpsc::UnboundedReceiver<Outgoing>,
+    /// Every [`WsSender`] was dropped; only the receiver keeps the connection alive
+    senders_gone: bool,
+    incoming: mpsc::Sender<WsMessage>,
+    /// Messages waiting for room in the receiver's buffer: connection events and the
+    /// [`WsMessage::Lagged`] count of market data dropped meanwhile
+    undelivered: VecDeque<WsMessage>,
+    subscriptions: Arc<Mutex<HashSet<Subscription>>>,
+    pending_posts: PendingPosts,
+}
+
+impl Connection {
+    async fn run(mut self, mut stream: WsStream) {
+        loop {
+            let reason = match self.pump(stream).await {
+                Exit::ClientGone => return,
+                Exit::Lost(reason) => reason,
+            };
+
+            // Requests in flight may or may not have been processed; fail them
+            self.pending_posts.lock().unwrap().clear();
+
+            let disconnected_at = Instant::now();
+            if !self.deliver(WsMessage::Gap { reason }) {
+                return;
+            }
+
+            let Some((new_stream, attempts)) = self.reconnect().await else {
+                return;
+            };
+            stream = new_stream;
+            #[cfg(feature = "metrics")]
+            crate::metrics::registry().ws_reconnect();
+
+            let reconnected = WsMessage::Reconnected {
+                attempts,
+                downtime: disconnected_at.elapsed(),
+            };
+            if !self.deliver(reconnected) {
+                return;
+            }
+        }
+    }
+
+    /// Pump messages between the socket and the client until either side goes away,
+    /// sending heartbeats and watching for a stale connection meanwhile
+    async fn pump(&mut self, stream: WsStream) -> Exit {
+        let (mut sink, mut source) = stream.split();
+
+        let mut heartbeat = tokio::time::interval_at(
+            Instant::now() + self.config.ws_ping_interval,
+            self.config.ws_ping_interval,
+        );
+        let stale = tokio::time::sleep(self.config.ws_stale_timeout);
+        tokio::pin!(stale);
+
+        loop {
+            tokio::select! {
+                request = self.outgoing.recv(), if !self.senders_gone => {
+                    let text = match request {
+                        Some(Outgoing::Subscription(text) | Outgoing::Post { text, .. }) => text,
+                        None => {
+                            self.senders_gone = true;
+                            if self.client_gone() {
+                                let _ = sink.close().await;
+                                return Exit::ClientGone;
+                            }
+                            continue;
+                        }
+                    };
+                    if let Err(e) = sink.send(Message::Text(text)).await {
+                        return Exit::Lost(format!("Failed to send request: {}", e));
+                    }
+                }
+                frame = source.next() => {
+                    stale.as_mut().reset(Instant::now() + self.config.ws_stale_timeout);
+                    match frame {
+                        Some(Ok(Message::Text(text))) => {
+                            if let Ok(message) = WsMessage::parse(&text) {
+                                #[cfg(feature = "metrics")]
+                                crate::metrics::registry().ws_message(message.channel());
+                                if !self.dispatch(message) {
+                                    return Exit::ClientGone;
+                                }
+                            }
+                        }
+                        Some(Ok(Message::Ping(payload))) => {
+                            if let Err(e) = sink.send(Message::Pong(payload)).await {
+                                return Exit::Lost(format!("Failed to send pong: {}", e));
+                            }
+                        }
+                        Some(Ok(Message::Close(_))) | None => {
+                            return Exit::Lost("Connection closed by server".to_string());
+                        }
+                        Some(Err(e)) => return Exit::Lost(format!("Connection error: {}", e)),
+                        Some(Ok(_)) => {}
+                    }
+                }
+                _ = heartbeat.tick() => {
+                    self.flush();
+                    if self.client_gone() {
+                        let _ = sink.close().await;
+                        return Exit::ClientGone;
+                    }
+                    if let Err(e) = sink.send(Message::Text(PING_REQUEST.to_string())).await {
+                        return Exit::Lost(format!("Failed to send ping: {}", e));
+                    }
+                }
+                _ = &mut stale => {
+                    return Exit::Lost(format!(
+                        "No message received for {:?}",
+                        self.config.ws_stale_timeout
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Re-open the socket with exponential backoff and replay every active subscription
+    ///
+    /// Returns the new stream and the number of attempts it took, or `None` if the
+    /// attempts are exhausted or the client has gone away.
+    async fn reconnect(&mut self) -> Option<(WsStream, u32)> {
+        let mut attempts = 0;
+
+        loop {
+            if self
+                .config
+                .ws_max_reconnect_attempts
+                .is_some_and(|max_attempts| attempts >= max_attempts)
+            {
+                return None;
+            }
+
+            tokio::time::sleep(self.backoff(attempts)).await;
+            attempts += 1;
+
+            if self.client_gone() {
+                return None;
+            }
+
+            let Ok(mut stream) = open(&self.config).await else {
+                continue;
+            };
+
+            // Queued subscription changes are already reflected in the replayed set; posts
+            // queued while disconnected were never sent
+            while let Ok(outgoing) = self.outgoing.try_recv() {
+                if let Outgoing::Post { id, .. } = outgoing {
+                    if let Some(response_tx) = self.pending_posts.lock().unwrap().remove(&id) {
+                        let _ = response_tx.send(None);
+                    }
+                }
+            }
+
+            if self.resubscribe(&mut stream).await.is_ok() {
+                return Some((stream, attempts));
+            }
+        }
+    }
+
+    /// Whether both the senders and the receiver have been dropped
+    fn client_gone(&self) -> bool {
+        (self.senders_gone || self.outgoing.is_closed()) && self.incoming.is_closed()
+    }
+
+    /// Queue a message for the receiver without waiting for it to make room
+    ///
+    /// Market data that does not fit is dropped and reported with [`WsMessage::Lagged`].
+    /// [`WsMessage::Gap`] and [`WsMessage::Reconnected`] are never dropped: they are held
+    /// back, in order, until the receiver has room. Returns `false` once the client has gone
+    /// away.
+    fn deliver(&mut self, message: WsMessage) -> bool {
+        if self.flush() {
+            match self.incoming.try_send(message) {
+                Ok(()) => return true,
+                Err(TrySendError::Full(message)) => self.hold_back(message),
+                Err(TrySendError::Closed(_)) => {}
+            }
+        } else if !self.incoming.is_closed() {
+            self.hold_back(message);
+        }
+        // A sender may still be posting without anyone reading the feed
+        !self.client_gone()
+    }
+
+    /// Hand held-back messages to the receiver while it has room
+    ///
+    /// Returns `true` once none are left.
+    fn flush(&mut self) -> bool {
+        while let Some(message) = self.undelivered.pop_front() {
+            match self.incoming.try_send(message) {
+                Ok(()) => {}
+                Err(TrySendError::Full(message)) => {
+                    self.undelivered.push_front(message);
+                    return false;
+                }
+                Err(TrySendError::Closed(_)) => {
+                    self.undelivered.clear();
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Keep a message that did not fit for [`Connection::flush`], or count it as dropped
+    fn hold_back(&mut self, message: WsMessage) {
+        match message {
+            WsMessage::Gap { .. } | WsMessage::Reconnected { .. } => self.undelivered.push_back(message),
+            _ => match self.undelivered.back_mut() {
+                Some(WsMessage::Lagged { dropped }) => *dropped += 1,
+                _ => self.undelivered.push_back(WsMessage::Lagged { dropped: 1 }),
+            },
+        }
+    }
+
+    /// Hand a `post` response to its waiting caller, or any other message to the client
+    ///
+    /// Returns `false` once the client has gone away.
+    fn dispatch(&mut self, message: WsMessage) -> bool {
+        let message = match message {
+            WsMessage::Post(post) => match self.pending_posts.lock().unwrap().remove(&post.id) {
+                Some(response_tx) => {
+                    let _ = response_tx.send(Some(post.response));
+                    return true;
+                }
+                None => WsMessage::Post(post),
+            },
+            message => message,
+        };
+        self.deliver(message)
+    }
+
+    async fn resubscribe(&self, stream: &mut WsStream) -> Result<()> {
+        let subscriptions: Vec<Subscription> = self.subscriptions.lock().unwrap().iter().cloned().collect();
+
+        for subscription in &subscriptions {
+            let request = serde_json::to_string(&SubscriptionRequest {
+                method: "subscribe",
+                subscription,
+            })?;
+            stream
+                .send(Message::Text(request))
+                .await
+                .map_err(|e| HyperLiquidError::WebSocketError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.min(16));
+        self.config
+            .ws_reconnect_base_delay
+            .saturating_mul(factor)
+            .min(self.config.ws_reconnect_max_delay)
+    }
+}
@@ -0,0 +1,360 @@
+//! Typed messages pushed by the WebSocket server.
+//!
+//! Every server message has the shape `{"channel": "...", "data": ...}`. Payloads reuse
+//! the REST response types where the shapes match (order book levels, candles, fills).
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::address::Address;
+use crate::info::all_mids::Price;
+use crate::info::candle_snapshot::CandleData;
+use crate::info::l2_book::{L2BookResponse, Level};
+use crate::info::ledger_updates::LedgerUpdate;
+use crate::info::meta::AssetCtx;
+use crate::info::user_fills::UserFillsResponse;
+use crate::types::OrderState;
+
+/// A message received on the WebSocket
+#[derive(Debug, Deserialize)]
+#[serde(tag = "channel", content = "data", rename_all = "camelCase")]
+pub enum WsMessage {
+    /// Acknowledgement of a `subscribe` / `unsubscribe` request
+    SubscriptionResponse(SubscriptionResponse),
+    AllMids(AllMids),
+    L2Book(L2BookResponse),
+    Trades(Vec<WsTrade>),
+    Candle(CandleData),
+    Bbo(WsBbo),
+    ActiveAssetCtx(WsActiveAssetCtx),
+    OrderUpdates(Vec<WsOrderUpdate>),
+    UserFills(WsUserFills),
+    UserFundings(WsUserFundings),
+    UserNonFundingLedgerUpdates(WsUserNonFundingLedgerUpdates),
+    /// Events of a `userEvents` subscription (sent on the `user` channel)
+    #[serde(rename = "user")]
+    UserEvents(WsUserEvent),
+    Notification(WsNotification),
+    /// Response to a `post` request
+    ///
+    /// Responses are routed to the pending [`crate::ws::client::WsSender::post_info`] or
+    /// [`crate::ws::client::WsSender::post_action`] call; only responses that arrive after
+    /// their request timed out are delivered here.
+    Post(WsPostResponse),
+    /// Reply to a `ping` heartbeat
+    Pong,
+    /// Error reported by the server, e.g. for an invalid subscription
+    Error(String),
+    /// A message on a channel this crate does not model, or with an unexpected payload
+    #[serde(skip_deserializing)]
+    Unknown { channel: String, data: serde_json::Value },
+    /// The connection was lost; messages may be missed until [`WsMessage::Reconnected`]
+    #[serde(skip_deserializing)]
+    Gap { reason: String },
+    /// The connection was re-established and every active subscription re-issued
+    ///
+    /// User feeds start over with an `isSnapshot` message, which should be reconciled
+    /// against local state rather than applied as new events.
+    #[serde(skip_deserializing)]
+    Reconnected { attempts: u32, downtime: Duration },
+    /// The receiver fell behind and this many messages were dropped because the buffer
+    /// (`config.ws_message_buffer`) was full
+    #[serde(skip_deserializing)]
+    Lagged { dropped: u64 },
+}
+
+/// Fallback shape used when a message does not match a known channel
+#[derive(Debug, Deserialize)]
+struct RawWsMessage {
+    channel: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+impl WsMessage {
+    /// Channel the message arrived on; empty for [`WsMessage::Gap`],
+    /// [`WsMessage::Reconnected`] and [`WsMessage::Lagged`], which are not sent by the server
+    pub fn channel(&self) -> &str {
+        match self {
+            WsMessage::SubscriptionResponse(_) => "subscriptionResponse",
+            WsMessage::AllMids(_) => "allMids",
+            WsMessage::L2Book(_) => "l2Book",
+            WsMessage::Trades(_) => "trades",
+            WsMessage::Candle(_) => "candle",
+            WsMessage::Bbo(_) => "bbo",
+            WsMessage::ActiveAssetCtx(_) => "activeAssetCtx",
+            WsMessage::OrderUpdates(_) => "orderUpdates",
+            WsMessage::UserFills(_) => "userFills",
+            WsMessage::UserFundings(_) => "userFundings",
+            WsMessage::UserNonFundingLedgerUpdates(_) => "userNonFundingLedgerUpdates",
+            WsMessage::UserEvents(_) => "user",
+            WsMessage::Notification(_) => "notification",
+            WsMessage::Post(_) => "post",
+            WsMessage::Pong => "pong",
+            WsMessage::Error(_) => "error",
+            WsMessage::Unknown { channel, .. } => channel,
+            WsMessage::Gap { .. } | WsMessage::Reconnected { .. } | WsMessage::Lagged { .. } => "",
+        }
+    }
+
+    /// Parse a text frame, falling back to [`WsMessage::Unknown`] for channels that are not
+    /// modelled or whose payload does not match the expected shape
+    pub fn parse(text: &str) -> serde_json::Result<Self> {
+        match serde_json::from_str::<WsMessage>(text) {
+            Ok(message) => Ok(message),
+            Err(error) => {
+                let raw: RawWsMessage = serde_json::from_str(text).map_err(|_| error)?;
+                Ok(WsMessage::Unknown { channel: raw.channel, data: raw.data })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubscriptionResponse {
+    pub method: String,
+    pub subscription: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WsPostResponse {
+    pub id: u64,
+    pub response: WsPostPayload,
+}
+
+/// Payload of a `post` response, tagged with the kind of request it answers
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", content = "payload", rename_all = "camelCase")]
+pub enum WsPostPayload {
+    /// `{"type": "<info type>", "data": ...}`, where `data` is the `/info` response body
+    Info(WsInfoPayload),
+    /// The `/exchange` response body, e.g. `{"status": "ok", "response": ...}`
+    Action(serde_json::Value),
+    /// The request could not be processed
+    Error(String),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WsInfoPayload {
+    #[serde(rename = "type")]
+    pub response_type: String,
+    pub data: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AllMids {
+    pub mids: HashMap<String, Price>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WsTrade {
+    pub coin: String,
+    pub side: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub px: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub sz: Decimal,
+    pub hash: String,
+    pub time: u64,
+    pub tid: u64,
+    /// Buyer and seller addresses
+    pub users: (Address, Address),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WsBbo {
+    pub coin: String,
+    pub time: u64,
+    /// Best bid and best ask; either side may be empty
+    pub bbo: (Option<Level>, Option<Level>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WsActiveAssetCtx {
+    pub coin: String,
+    pub ctx: AssetCtx,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsOrderUpdate {
+    pub order: WsBasicOrder,
+    pub status: OrderState,
+    pub status_timestamp: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsBasicOrder {
+    pub coin: String,
+    pub side: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub limit_px: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub sz: Decimal,
+    pub oid: u64,
+    pub timestamp: u64,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub orig_sz: Decimal,
+    #[serde(default)]
+    pub cloid: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsUserFills {
+    /// Set on the first message after subscribing, which carries recent history
+    #[serde(default)]
+    pub is_snapshot: bool,
+    pub user: Address,
+    pub fills: Vec<UserFillsResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsUserFundings {
+    #[serde(default)]
+    pub is_snapshot: bool,
+    pub user: Address,
+    pub fundings: Vec<WsUserFunding>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsUserFunding {
+    pub time: u64,
+    pub coin: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub usdc: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub szi: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub funding_rate: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WsUserNonFundingLedgerUpdates {
+    #[serde(default)]
+    pub is_snapshot: bool,
+    pub user: Address,
+    pub non_funding_ledger_updates: Vec<LedgerUpdate>,
+}
+
+/// Payload of the `user` channel
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WsUserEvent {
+    Fills(Vec<UserFillsResponse>),
+    Funding(WsUserFunding),
+    Liquidation(WsLiquidation),
+    NonUserCancel(Vec<WsNonUserCancel>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WsLiquidation {
+    pub lid: u64,
+    pub liquidator: Address,
+    pub liquidated_user: Address,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub liquidated_ntl_pos: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub liquidated_account_value: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WsNonUserCancel {
+    pub coin: String,
+    pub oid: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WsNotification {
+    pub notification: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::info::ledger_updates::LedgerDelta;
+
+    #[test]
+    fn test_parse_market_data() {
+        let message = WsMessage::parse(r#"{"channel":"allMids","data":{"mids":{"BTC":"50000.5"}}}"#).unwrap();
+        assert!(matches!(message, WsMessage::AllMids(mids) if mids.mids["BTC"].0 == Decimal::new(500005, 1)));
+
+        let message = WsMessage::parse(
+            r#"{"channel":"l2Book","data":{"coin":"BTC","time":1,"levels":[[{"px":"1","sz":"2","n":3}],[]]}}"#,
+        )
+        .unwrap();
+        assert!(matches!(message, WsMessage::L2Book(book) if book.coin == "BTC" && book.levels[0][0].n == 3));
+
+        let message = WsMessage::parse(
+            r#"{"channel":"bbo","data":{"coin":"ETH","time":2,"bbo":[null,{"px":"3000","sz":"1","n":1}]}}"#,
+        )
+        .unwrap();
+        assert!(matches!(message, WsMessage::Bbo(bbo) if bbo.bbo.0.is_none() && bbo.bbo.1.is_some()));
+
+        let message = WsMessage::parse(
+            r#"{"channel":"activeAssetCtx","data":{"coin":"BTC","ctx":{"funding":"0.0000125","openInterest":"100",
+                "prevDayPx":"49000","dayNtlVlm":"1000000","premium":"0.0001","oraclePx":"50000","markPx":"50001",
+                "midPx":"50000.5","impactPxs":["50000","50001"],"dayBaseVlm":"20"}}}"#,
+        )
+        .unwrap();
+        assert!(matches!(message, WsMessage::ActiveAssetCtx(ctx) if ctx.ctx.mark_px == Decimal::new(50001, 0)));
+
+        let message = WsMessage::parse(r#"{"channel":"pong"}"#).unwrap();
+        assert!(matches!(message, WsMessage::Pong));
+    }
+
+    #[test]
+    fn test_parse_post_response() {
+        let message = WsMessage::parse(
+            r#"{"channel":"post","data":{"id":7,"response":{"type":"info","payload":{"type":"l2Book","data":{"coin":"BTC"}}}}}"#,
+        )
+        .unwrap();
+        assert!(matches!(message, WsMessage::Post(WsPostResponse { id: 7, response: WsPostPayload::Info(info) })
+            if info.response_type == "l2Book" && info.data["coin"] == "BTC"));
+
+        let message = WsMessage::parse(
+            r#"{"channel":"post","data":{"id":8,"response":{"type":"error","payload":"bad request"}}}"#,
+        )
+        .unwrap();
+        assert!(matches!(message, WsMessage::Post(WsPostResponse { id: 8, response: WsPostPayload::Error(e) }) if e == "bad request"));
+    }
+
+    #[test]
+    fn test_parse_user_events() {
+        let message = WsMessage::parse(
+            r#"{"channel":"user","data":{"nonUserCancel":[{"coin":"BTC","oid":12}]}}"#,
+        )
+        .unwrap();
+        assert!(matches!(message, WsMessage::UserEvents(WsUserEvent::NonUserCancel(cancels)) if cancels[0].oid == 12));
+
+        let message = WsMessage::parse(
+            r#"{"channel":"userFundings","data":{"isSnapshot":true,"user":"0x0000000000000000000000000000000000000000","fundings":[
+                {"time":1,"coin":"ETH","usdc":"-1.5","szi":"2","fundingRate":"0.0001"}]}}"#,
+        )
+        .unwrap();
+        assert!(matches!(message, WsMessage::UserFundings(f) if f.is_snapshot && f.fundings.len() == 1));
+
+        let message = WsMessage::parse(
+            r#"{"channel":"userNonFundingLedgerUpdates","data":{"isSnapshot":false,"user":"0x0000000000000000000000000000000000000000","nonFundingLedgerUpdates":[
+                {"time":1,"hash":"0xabc","delta":{"type":"withdraw","usdc":"99","fee":"1","nonce":5}}]}}"#,
+        )
+        .unwrap();
+        assert!(matches!(message, WsMessage::UserNonFundingLedgerUpdates(u)
+            if matches!(u.non_funding_ledger_updates[0].delta, LedgerDelta::Withdraw { nonce: Some(5), .. })));
+    }
+
+    #[test]
+    fn test_parse_unknown_channel() {
+        let message = WsMessage::parse(r#"{"channel":"webData2","data":{"foo":1}}"#).unwrap();
+        assert!(matches!(message, WsMessage::Unknown { channel, .. } if channel == "webData2"));
+
+        assert!(WsMessage::parse("not json").is_err());
+    }
+}
@@ -0,0 +1,216 @@
+//! Subscription descriptors sent in `subscribe` / `unsubscribe` requests.
+
+use serde::{Deserialize, Serialize};
+
+use crate::address::{Address, IntoAddress};
+use crate::errors::{validate_coin_symbol, validate_interval, HyperLiquidError, Result};
+
+/// A WebSocket data feed
+///
+/// Serializes to the `subscription` object of a `subscribe` request, e.g.
+/// `{"type": "l2Book", "coin": "BTC"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Subscription {
+    /// Mid prices for all coins
+    AllMids,
+    /// Order book snapshots for a coin
+    #[serde(rename_all = "camelCase")]
+    L2Book {
+        coin: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        n_sig_figs: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mantissa: Option<u32>,
+    },
+    /// Public trades for a coin
+    Trades { coin: String },
+    /// Candle updates for a coin and interval
+    Candle { coin: String, interval: String },
+    /// Best bid and offer for a coin
+    Bbo { coin: String },
+    /// Asset context (funding, open interest, prices) for a coin
+    ActiveAssetCtx { coin: String },
+    /// Status changes of a user's orders
+    OrderUpdates { user: Address },
+    /// A user's fills, starting with a snapshot
+    #[serde(rename_all = "camelCase")]
+    UserFills {
+        user: Address,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        aggregate_by_time: Option<bool>,
+    },
+    /// A user's funding payments, starting with a snapshot
+    UserFundings { user: Address },
+    /// A user's deposits, withdrawals, transfers and liquidations, starting with a snapshot
+    UserNonFundingLedgerUpdates { user: Address },
+    /// A user's fills, fundings, liquidations and non-user cancels
+    UserEvents { user: Address },
+    /// Notifications addressed to a user
+    Notification { user: Address },
+}
+
+impl Subscription {
+    pub fn l2_book(coin: impl Into<String>) -> Self {
+        Subscription::L2Book { coin: coin.into(), n_sig_figs: None, mantissa: None }
+    }
+
+    pub fn trades(coin: impl Into<String>) -> Self {
+        Subscription::Trades { coin: coin.into() }
+    }
+
+    pub fn candle(coin: impl Into<String>, interval: impl Into<String>) -> Self {
+        Subscription::Candle { coin: coin.into(), interval: interval.into() }
+    }
+
+    pub fn bbo(coin: impl Into<String>) -> Self {
+        Subscription::Bbo { coin: coin.into() }
+    }
+
+    pub fn active_asset_ctx(coin: impl Into<String>) -> Self {
+        Subscription::ActiveAssetCtx { coin: coin.into() }
+    }
+
+    pub fn order_updates(user: impl IntoAddress) -> Result<Self> {
+        Ok(Subscription::OrderUpdates { user: user.into_address()? })
+    }
+
+    pub fn user_fills(user: impl IntoAddress) -> Result<Self> {
+        Ok(Subscription::UserFills { user: user.into_address()?, aggregate_by_time: None })
+    }
+
+    pub fn user_fundings(user: impl IntoAddress) -> Result<Self> {
+        Ok(Subscription::UserFundings { user: user.into_address()? })
+    }
+
+    pub fn user_non_funding_ledger_updates(user: impl IntoAddress) -> Result<Self> {
+        Ok(Subscription::UserNonFundingLedgerUpdates { user: user.into_address()? })
+    }
+
+    pub fn user_events(user: impl IntoAddress) -> Result<Self> {
+        Ok(Subscription::UserEvents { user: user.into_address()? })
+    }
+
+    pub fn notification(user: impl IntoAddress) -> Result<Self> {
+        Ok(Subscription::Notification { user: user.into_address()? })
+    }
+
+    /// Validate the coin or interval carried by the subscription; user addresses are
+    /// already checked when the subscription is built
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Subscription::AllMids => Ok(()),
+            Subscription::L2Book { coin, .. }
+            | Subscription::Trades { coin }
+            | Subscription::Bbo { coin }
+            | Subscription::ActiveAssetCtx { coin } => validate_feed_coin(coin),
+            Subscription::Candle { coin, interval } => {
+                validate_feed_coin(coin)?;
+                validate_interval(interval)
+            }
+            Subscription::OrderUpdates { .. }
+            | Subscription::UserFills { .. }
+            | Subscription::UserFundings { .. }
+            | Subscription::UserNonFundingLedgerUpdates { .. }
+            | Subscription::UserEvents { .. }
+            | Subscription::Notification { .. } => Ok(()),
+        }
+    }
+}
+
+/// Validate a coin as the WebSocket feeds name it
+///
+/// Besides perp symbols like `BTC`, feeds accept spot pairs by index (`@107`) or by name
+/// (`PURR/USDC`), and perps on builder-deployed dexs as `dex:COIN`.
+fn validate_feed_coin(coin: &str) -> Result<()> {
+    let invalid = |reason: &str| Err(HyperLiquidError::InvalidCoin(format!("Invalid coin '{}': {}", coin, reason)));
+
+    if let Some(index) = coin.strip_prefix('@') {
+        if index.is_empty() || !index.chars().all(|c| c.is_ascii_digit()) {
+            return invalid("a spot index must be '@' followed by digits");
+        }
+        return Ok(());
+    }
+    if let Some((base, quote)) = coin.split_once('/') {
+        validate_coin_symbol(base)?;
+        return validate_coin_symbol(quote);
+    }
+    if let Some((dex, symbol)) = coin.split_once(':') {
+        if dex.is_empty() || !dex.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return invalid("the dex name must be non-empty and alphanumeric");
+        }
+        return validate_coin_symbol(symbol);
+    }
+    validate_coin_symbol(coin)
+}
+
+/// Outgoing `subscribe` / `unsubscribe` request
+#[derive(Debug, Serialize)]
+pub(crate) struct SubscriptionRequest<'a> {
+    pub method: &'static str,
+    pub subscription: &'a Subscription,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscription_serialization() {
+        assert_eq!(
+            serde_json::to_value(Subscription::AllMids).unwrap(),
+            serde_json::json!({"type": "allMids"})
+        );
+        assert_eq!(
+            serde_json::to_value(Subscription::l2_book("BTC")).unwrap(),
+            serde_json::json!({"type": "l2Book", "coin": "BTC"})
+        );
+        assert_eq!(
+            serde_json::to_value(Subscription::candle("ETH", "1m")).unwrap(),
+            serde_json::json!({"type": "candle", "coin": "ETH", "interval": "1m"})
+        );
+        assert_eq!(
+            serde_json::to_value(Subscription::UserFills {
+                user: Address::ZERO,
+                aggregate_by_time: Some(true),
+            })
+            .unwrap(),
+            serde_json::json!({
+                "type": "userFills",
+                "user": "0x0000000000000000000000000000000000000000",
+                "aggregateByTime": true
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(Subscription::user_non_funding_ledger_updates(Address::ZERO).unwrap()).unwrap()["type"],
+            "userNonFundingLedgerUpdates"
+        );
+    }
+
+    #[test]
+    fn test_subscription_validation() {
+        assert!(Subscription::AllMids.validate().is_ok());
+        assert!(Subscription::trades("BTC").validate().is_ok());
+        assert!(Subscription::candle("BTC", "2m").validate().is_err());
+        assert!(Subscription::bbo("").validate().is_err());
+        assert!(Subscription::user_events("0x123").is_err());
+        assert!(Subscription::user_events(Address::ZERO).unwrap().validate().is_ok());
+    }
+
+    #[test]
+    fn test_spot_and_dex_coins_validate() {
+        assert!(Subscription::l2_book("@107").validate().is_ok());
+        assert!(Subscription::trades("PURR/USDC").validate().is_ok());
+        assert!(Subscription::candle("xyz:XYZ100", "1m").validate().is_ok());
+        assert!(Subscription::bbo("@0").validate().is_ok());
+
+        assert!(Subscription::l2_book("@").validate().is_err());
+        assert!(Subscription::l2_book("@1a").validate().is_err());
+        assert!(Subscription::trades("PURR/").validate().is_err());
+        assert!(Subscription::trades("/USDC").validate().is_err());
+        assert!(Subscription::trades("PURR/USDC/X").validate().is_err());
+        assert!(Subscription::bbo(":BTC").validate().is_err());
+        assert!(Subscription::bbo("xyz:").validate().is_err());
+        assert!(Subscription::bbo("x-y:BTC").validate().is_err());
+    }
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "blocking")]
+
+use hyperliquid::blocking::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::HyperLiquidError;
+use hyperliquid::retry::RetryPolicy;
+use hyperliquid::transport::MemoryTransport;
+use rust_decimal::Decimal;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[cfg(test)]
+mod blocking_tests {
+    use super::*;
+
+    fn config() -> HyperLiquidConfig {
+        HyperLiquidConfig::new()
+            .with_max_retries(2)
+            .with_retry_policy(RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(1)))
+    }
+
+    #[test]
+    fn test_blocking_calls_over_http() {
+        // The mock server runs on its own runtime; the client is called from plain threads
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mock_server = runtime.block_on(async {
+            let mock_server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path("/info"))
+                .and(body_partial_json(json!({"type": "l2Book", "coin": "BTC"})))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({"coin": "BTC", "time": 1, "levels": [[], []]})))
+                .expect(2)
+                .mount(&mock_server)
+                .await;
+            mock_server
+        });
+
+        let client = HyperLiquidClient::with_config(config().with_base_url(mock_server.uri())).unwrap();
+        assert_eq!(client.get_l2_book("BTC").unwrap().coin, "BTC");
+
+        let clone = client.clone();
+        std::thread::spawn(move || clone.get_l2_book("BTC").unwrap()).join().unwrap();
+    }
+
+    #[test]
+    fn test_blocking_shares_validation_and_retries() {
+        let transport = Arc::new(
+            MemoryTransport::new()
+                .with_response("allMids", json!({"BTC": "50000.0"}))
+                .with_status("meta", 503, "maintenance"),
+        );
+        let client = HyperLiquidClient::with_config(config()).unwrap().with_transport(transport.clone());
+
+        assert_eq!(client.get_all_mids().unwrap().prices["BTC"].0, Decimal::new(50_000, 0));
+        assert!(matches!(client.get_user_fees("not-an-address"), Err(HyperLiquidError::InvalidAddress(_))));
+
+        let error = client.get_meta(None).unwrap_err();
+        assert!(matches!(error, HyperLiquidError::ServerError { status: 503, retries: 2, .. }));
+        assert_eq!(transport.requests().len(), 4);
+    }
+}
@@ -0,0 +1,134 @@
+use hyperliquid::config::{HyperLiquidConfig, TESTNET_API_URL};
+use hyperliquid::errors::HyperLiquidError;
+use hyperliquid::network::Network;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Serializes tests, since loading a file also reads `HYPERLIQUID_*` variables
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+const CONFIG_TOML: &str = r#"
+timeout_secs = 10
+pool_max_idle_per_host = 4
+
+[profiles.prod]
+network = "mainnet"
+max_retries = 5
+
+[profiles.testnet]
+network = "testnet"
+tcp_keepalive_secs = 15
+"#;
+
+fn write_config(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("hyperliquid-config-{}-{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+fn config_error(result: hyperliquid::errors::Result<HyperLiquidConfig>) -> String {
+    match result {
+        Err(HyperLiquidError::ConfigError(message)) => message,
+        other => panic!("Expected a configuration error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[cfg(test)]
+mod config_file_tests {
+    use super::*;
+
+    #[test]
+    fn test_toml_profiles_layer_over_top_level() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let path = write_config("profiles.toml", CONFIG_TOML);
+
+        let config = HyperLiquidConfig::from_file(&path).unwrap();
+        assert_eq!((config.timeout, config.pool_max_idle_per_host), (Duration::from_secs(10), 4));
+        assert_eq!(config.network, Network::Mainnet);
+
+        let config = HyperLiquidConfig::from_file_profile(&path, "testnet").unwrap();
+        assert_eq!(config.network, Network::Testnet);
+        assert_eq!(config.base_url, TESTNET_API_URL);
+        assert_eq!((config.timeout, config.tcp_keepalive), (Duration::from_secs(10), Duration::from_secs(15)));
+
+        let config = HyperLiquidConfig::from_file_profile(&path, "prod").unwrap().with_max_retries(1);
+        assert_eq!(config.max_retries, 1, "Builder methods override the file");
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_json_file() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let path = write_config(
+            "config.json",
+            r#"{"ws_reconnect_base_delay_ms": 100, "default_headers": {"X-Desk": "arb"}, "proxy": "socks5h://127.0.0.1:1080", "resolve_overrides": {"api.hyperliquid.xyz": "203.0.113.10"}, "profiles": {"local": {"network": "local", "base_url": "http://127.0.0.1:9000"}}}"#,
+        );
+
+        let config = HyperLiquidConfig::from_file_profile(&path, "local").unwrap();
+        assert_eq!(config.ws_reconnect_base_delay, Duration::from_millis(100));
+        assert_eq!(config.ws_url, "ws://127.0.0.1:9000/ws");
+        assert_eq!(config.default_headers["X-Desk"], "arb");
+        assert_eq!(config.proxy.as_deref(), Some("socks5h://127.0.0.1:1080"));
+        assert_eq!(config.resolve_overrides["api.hyperliquid.xyz"].to_string(), "203.0.113.10");
+        assert!(!config.is_mainnet());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_env_overrides_file() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let path = write_config("env.toml", CONFIG_TOML);
+
+        unsafe {
+            std::env::set_var("HYPERLIQUID_PROFILE", "prod");
+            std::env::set_var("HYPERLIQUID_MAX_RETRIES", "7");
+        }
+        let config = HyperLiquidConfig::from_file(&path);
+        unsafe {
+            std::env::set_var("HYPERLIQUID_MAX_RETRIES", "many");
+        }
+        let message = config_error(HyperLiquidConfig::from_file(&path));
+        let env_message = config_error(HyperLiquidConfig::from_env());
+        unsafe {
+            std::env::remove_var("HYPERLIQUID_PROFILE");
+            std::env::remove_var("HYPERLIQUID_MAX_RETRIES");
+        }
+
+        assert_eq!(config.unwrap().max_retries, 7);
+        assert!(message.contains("HYPERLIQUID_MAX_RETRIES=\"many\""), "{}", message);
+        assert!(env_message.contains("HYPERLIQUID_MAX_RETRIES=\"many\""), "{}", env_message);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_files_are_rejected() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let cases = [
+            ("unknown.toml", "timeout = 10", "unknown field `timeout`"),
+            ("type.toml", "max_retries = -1", "`max_retries`: invalid value: integer `-1`"),
+            ("network.toml", "network = \"devnet\"", "Unknown network 'devnet'"),
+            ("zero.toml", "timeout_secs = 0", "timeout_secs must be greater than 0"),
+            ("url.toml", "base_url = \"api.hyperliquid.xyz\"", "must start with http:// or https://"),
+            ("ping.toml", "ws_ping_interval_secs = 90", "ws_ping_interval_secs (90)"),
+            ("resolve.toml", "[resolve_overrides]\n\"api.hyperliquid.xyz\" = \"203.0.113.10:443\"", "invalid IP address syntax"),
+            ("config.yaml", "timeout_secs: 10", "expected a .toml or .json file"),
+        ];
+
+        for (name, contents, expected) in cases {
+            let path = write_config(name, contents);
+            let message = config_error(HyperLiquidConfig::from_file(&path));
+            assert!(message.contains(expected), "{}: {}", name, message);
+            std::fs::remove_file(path).unwrap();
+        }
+
+        let path = write_config("missing-profile.toml", CONFIG_TOML);
+        let message = config_error(HyperLiquidConfig::from_file_profile(&path, "staging"));
+        assert!(message.contains("no profile 'staging'"), "{}", message);
+        assert!(message.contains("prod") && message.contains("testnet"), "{}", message);
+        std::fs::remove_file(path).unwrap();
+    }
+}
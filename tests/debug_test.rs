@@ -16,16 +16,16 @@ mod debug_tests {
         // Simple success response
         Mock::given(method("POST"))
             .and(path("/info"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&json!({"BTC": "50000"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000"})))
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(1)
             .with_timeout(Duration::from_secs(5));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         match &result {
@@ -51,16 +51,16 @@ mod debug_tests {
         // Subsequent requests succeed
         Mock::given(method("POST"))
             .and(path("/info"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&json!({"BTC": "50000"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000"})))
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(2)
             .with_timeout(Duration::from_secs(10));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         println!("Starting retry test...");
         let result = client.get_all_mids().await;
@@ -81,16 +81,16 @@ mod debug_tests {
         // All requests succeed
         Mock::given(method("POST"))
             .and(path("/info"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&json!({"BTC": "50000"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000"})))
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(1)
             .with_timeout(Duration::from_secs(5));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         // Make 3 simple concurrent requests
         let mut handles = vec![];
@@ -113,5 +113,3 @@ mod debug_tests {
         assert_eq!(successes, 3, "All simple concurrent requests should succeed");
     }
 }
-
-use futures;
\ No newline at end of file
@@ -1,9 +1,11 @@
 use hyperliquid::client::HyperLiquidClient;
 use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::HyperLiquidError;
 use serde_json::json;
 use std::time::Duration;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
+use futures::future;
 
 #[cfg(test)]
 mod error_scenario_tests {
@@ -19,7 +21,7 @@ mod error_scenario_tests {
             "0x123", // Too short
             "1234567890123456789012345678901234567890", // No 0x prefix
             "0x123456789012345678901234567890123456789G", // Invalid hex
-            "0X1234567890123456789012345678901234567890", // Wrong case prefix
+            "0xabcdefABCDEF1234567890123456789012345678", // Bad checksum
         ];
         
         for invalid_addr in invalid_addresses {
@@ -61,7 +63,7 @@ mod error_scenario_tests {
             .with_max_retries(1)
             .with_timeout(Duration::from_secs(5));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_err(), "Should fail with connection error");
@@ -91,10 +93,10 @@ mod error_scenario_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(1);
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_err(), "Should fail with JSON parsing error");
@@ -125,10 +127,10 @@ mod error_scenario_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(1);
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_err(), "Should fail with deserialization error");
@@ -160,11 +162,11 @@ mod error_scenario_tests {
                 .await;
             
             let config = HyperLiquidConfig::new()
-                .with_base_url(&mock_server.uri())
+                .with_base_url(mock_server.uri())
                 .with_max_retries(1)
                 .with_timeout(Duration::from_secs(5));
             
-            let client = HyperLiquidClient::with_config(config);
+            let client = HyperLiquidClient::with_config(config).unwrap();
             
             let result = client.get_all_mids().await;
             assert!(result.is_err(), "Status {} should result in error", status_code);
@@ -179,6 +181,94 @@ mod error_scenario_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_http_errors_map_to_typed_variants() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "allMids"})))
+            .respond_with(ResponseTemplate::new(422).set_body_string("Failed to deserialize the JSON body"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "meta"})))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "spotMeta"})))
+            .respond_with(ResponseTemplate::new(503).set_body_string("maintenance"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "predictedFundings"})))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<html>not json</html>"))
+            .mount(&mock_server)
+            .await;
+
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(0)
+            .with_timeout(Duration::from_secs(5));
+        let client = HyperLiquidClient::with_config(config).unwrap();
+
+        match client.get_all_mids().await.unwrap_err() {
+            HyperLiquidError::ApiError { status, message } => {
+                assert_eq!(status, 422);
+                assert!(message.contains("deserialize"));
+            }
+            other => panic!("Expected ApiError, got {:?}", other),
+        }
+        assert!(matches!(
+            client.get_meta(None).await.unwrap_err(),
+            HyperLiquidError::RateLimitExceeded { retries: 0 }
+        ));
+        assert!(matches!(
+            client.get_spot_meta().await.unwrap_err(),
+            HyperLiquidError::ServerError { status: 503, ref message, .. } if message == "maintenance"
+        ));
+        assert!(matches!(
+            client.get_predicted_fundings().await.unwrap_err(),
+            HyperLiquidError::DecodeError { ref body, .. } if body == "<html>not json</html>"
+        ));
+        assert!(matches!(
+            client.get_portfolio("invalid_address").await.unwrap_err(),
+            HyperLiquidError::InvalidAddress(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transport_errors_map_to_typed_variants() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let config = HyperLiquidConfig::new().with_base_url(url).with_max_retries(0);
+        let client = HyperLiquidClient::with_config(config).unwrap();
+        assert!(matches!(
+            client.get_all_mids().await.unwrap_err(),
+            HyperLiquidError::ConnectionError { retries: 0, .. }
+        ));
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(5)))
+            .mount(&mock_server)
+            .await;
+
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(0)
+            .with_timeout(Duration::from_millis(200));
+        let client = HyperLiquidClient::with_config(config).unwrap();
+        assert!(matches!(client.get_all_mids().await.unwrap_err(), HyperLiquidError::Timeout));
+    }
+
     #[tokio::test]
     async fn test_timeout_scenarios() {
         let mock_server = MockServer::start().await;
@@ -189,17 +279,17 @@ mod error_scenario_tests {
             .respond_with(
                 ResponseTemplate::new(200)
                     .set_delay(Duration::from_secs(30)) // Much longer than timeout
-                    .set_body_json(&json!({"test": "data"}))
+                    .set_body_json(json!({"test": "data"}))
             )
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(1)
             .with_timeout(Duration::from_secs(2)); // Short timeout
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let start = std::time::Instant::now();
         let result = client.get_all_mids().await;
@@ -208,11 +298,10 @@ mod error_scenario_tests {
         assert!(result.is_err(), "Should timeout");
         assert!(elapsed < Duration::from_secs(10), "Should not wait for full response delay");
         
-        let error_message = result.unwrap_err().to_string();
+        let error = result.unwrap_err();
         assert!(
-            error_message.contains("timeout") || 
-            error_message.contains("retries"),
-            "Error should indicate timeout"
+            matches!(error, HyperLiquidError::Timeout),
+            "Error should indicate timeout, got {}", error
         );
     }
 
@@ -232,9 +321,9 @@ mod error_scenario_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri());
+            .with_base_url(mock_server.uri());
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_err(), "Should fail with empty response body");
@@ -256,16 +345,16 @@ mod error_scenario_tests {
         // All subsequent requests succeed (including retries)
         Mock::given(method("POST"))
             .and(path("/info"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&json!({"test": "success"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000.0"})))
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(2) // Allow retries
             .with_timeout(Duration::from_secs(10));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         // Make 3 concurrent requests
         let mut handles = vec![];
@@ -319,16 +408,16 @@ mod error_scenario_tests {
             .respond_with(
                 ResponseTemplate::new(200)
                     .set_delay(Duration::from_secs(1)) // Slow responses to hold connections
-                    .set_body_json(&json!({"test": "data"}))
+                    .set_body_json(json!({"BTC": "50000.0"}))
             )
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_timeout(Duration::from_secs(5));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         // Create many concurrent requests to potentially exhaust connection pool
         let mut handles = vec![];
@@ -350,5 +439,3 @@ mod error_scenario_tests {
         assert!(successful_requests > 40, "Connection pooling should handle concurrent requests");
     }
 }
-
-use futures::future;
\ No newline at end of file
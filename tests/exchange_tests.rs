@@ -0,0 +1,457 @@
+use hyperliquid::action_budget::ActionBudget;
+use hyperliquid::client::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::{HyperLiquidError, Result};
+use hyperliquid::exchange::action::{ExchangeAction, ExchangeResponseData};
+use hyperliquid::exchange::cancel::{CancelByCloidRequest, CancelRequest, CancelStatus};
+use hyperliquid::exchange::modify::ModifyRequest;
+use hyperliquid::exchange::order::{OrderRequest, OrderStatus, Tif};
+use hyperliquid::exchange::signing::Wallet;
+use hyperliquid::retry::RetryPolicy;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TEST_PRIVATE_KEY: &str = "0x0123456789012345678901234567890123456789012345678901234567890123";
+
+#[derive(Serialize)]
+struct DummyAction {
+    #[serde(rename = "type")]
+    action_type: String,
+    num: u64,
+}
+
+impl ExchangeAction for DummyAction {
+    fn request_count(&self) -> u64 {
+        1
+    }
+}
+
+fn dummy_action() -> DummyAction {
+    DummyAction {
+        action_type: "dummy".to_string(),
+        num: 100_000_000_000,
+    }
+}
+
+#[cfg(test)]
+mod exchange_tests {
+    use super::*;
+
+    async fn signed_client(mock_server: &MockServer) -> HyperLiquidClient {
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(0)
+            .with_timeout(Duration::from_secs(5));
+
+        HyperLiquidClient::with_config(config).unwrap()
+            .with_wallet(Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_post_action_sends_signed_envelope() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {"type": "default"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = signed_client(&mock_server).await;
+        let result: ExchangeResponseData<serde_json::Value> = client.post_action(dummy_action()).await.unwrap();
+        assert_eq!(result.response_type, "default");
+        assert!(result.data.is_none());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["action"], json!({"type": "dummy", "num": 100_000_000_000u64}));
+        assert!(body["nonce"].as_u64().unwrap() > 0);
+        assert!(body["vaultAddress"].is_null());
+        assert_eq!(body["signature"]["r"].as_str().unwrap().len(), 66);
+        assert_eq!(body["signature"]["s"].as_str().unwrap().len(), 66);
+        assert!(matches!(body["signature"]["v"].as_u64(), Some(27) | Some(28)));
+    }
+
+    #[tokio::test]
+    async fn test_post_action_includes_vault_address() {
+        let mock_server = MockServer::start().await;
+        let vault = "0x1719884eb866cb12b2287399b15f7db5e7d775ea";
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {"type": "default"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = signed_client(&mock_server).await.with_vault_address(vault).unwrap();
+        let _: ExchangeResponseData<serde_json::Value> = client.post_action(dummy_action()).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["vaultAddress"], vault);
+    }
+
+    #[tokio::test]
+    async fn test_post_action_surfaces_exchange_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "err",
+                "response": "User or API Wallet 0x14791697260e4c9a71f18484c9f997b308e59325 does not exist."
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = signed_client(&mock_server).await;
+        let result: Result<ExchangeResponseData<serde_json::Value>> = client.post_action(dummy_action()).await;
+
+        match result.unwrap_err() {
+            HyperLiquidError::ExchangeError(message) => assert!(message.contains("does not exist")),
+            other => panic!("Expected exchange error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_action_is_not_retried_after_reaching_server() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(502).set_body_string("bad gateway"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(3)
+            .with_retry_policy(RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(1)));
+        let client = HyperLiquidClient::with_config(config).unwrap()
+            .with_wallet(Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap());
+
+        let result: Result<ExchangeResponseData<serde_json::Value>> = client.post_action(dummy_action()).await;
+        assert!(matches!(result.unwrap_err(), HyperLiquidError::ServerError { status: 502, retries: 0, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_post_action_requires_wallet() {
+        let mock_server = MockServer::start().await;
+
+        let config = HyperLiquidConfig::new().with_base_url(mock_server.uri());
+        let client = HyperLiquidClient::with_config(config).unwrap();
+
+        let result: Result<ExchangeResponseData<serde_json::Value>> = client.post_action(dummy_action()).await;
+        assert!(result.unwrap_err().to_string().contains("No wallet configured"));
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_place_orders_parses_statuses() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {
+                    "type": "order",
+                    "data": {
+                        "statuses": [
+                            {"resting": {"oid": 77738308}},
+                            {"filled": {"totalSz": "0.02", "avgPx": "1891.4", "oid": 77747314}},
+                            {"error": "Order must have minimum value of $10."}
+                        ]
+                    }
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = signed_client(&mock_server).await;
+        let orders = vec![
+            OrderRequest::limit(0, true, Decimal::new(50_000, 0), Decimal::new(1, 2), Tif::Alo),
+            OrderRequest::limit(4, true, Decimal::new(18_914, 1), Decimal::new(2, 2), Tif::Ioc),
+            OrderRequest::limit(4, false, Decimal::new(1, 0), Decimal::new(1, 2), Tif::Gtc)
+                .with_cloid("0x00000000000000000000000000000001"),
+        ];
+
+        let statuses = client.place_orders(orders).await.unwrap();
+        assert_eq!(statuses.len(), 3);
+        assert!(matches!(&statuses[0], OrderStatus::Resting(resting) if resting.oid == 77738308));
+        assert!(matches!(&statuses[1], OrderStatus::Filled(filled) if filled.oid == 77747314));
+        assert!(matches!(&statuses[2], OrderStatus::Error(_)));
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["action"]["type"], "order");
+        assert_eq!(body["action"]["grouping"], "na");
+        assert_eq!(
+            body["action"]["orders"][0],
+            json!({"a": 0, "b": true, "p": "50000", "s": "0.01", "r": false, "t": {"limit": {"tif": "Alo"}}})
+        );
+        assert_eq!(body["action"]["orders"][2]["c"], "0x00000000000000000000000000000001");
+    }
+
+    #[tokio::test]
+    async fn test_place_order_rejects_invalid_order_before_sending() {
+        let mock_server = MockServer::start().await;
+        let client = signed_client(&mock_server).await;
+
+        let order = OrderRequest::limit(0, true, Decimal::new(50_000, 0), Decimal::ZERO, Tif::Gtc);
+        let result = client.place_order(order).await;
+
+        assert!(result.unwrap_err().to_string().contains("Invalid order size"));
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_action_budget_counts_batches_and_blocks_at_cap() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {"type": "order", "data": {"statuses": [
+                    {"resting": {"oid": 1}}, {"resting": {"oid": 2}}
+                ]}}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let budget = Arc::new(ActionBudget::new(9_997, 10_000, Decimal::ZERO));
+        let client = signed_client(&mock_server).await.with_action_budget(budget.clone());
+        let orders = || vec![
+            OrderRequest::limit(0, true, Decimal::new(50_000, 0), Decimal::new(1, 2), Tif::Alo),
+            OrderRequest::limit(0, true, Decimal::new(49_000, 0), Decimal::new(1, 2), Tif::Alo),
+        ];
+
+        client.place_orders(orders()).await.unwrap();
+        assert_eq!(budget.snapshot().remaining(), 1, "A batch of two orders counts twice");
+
+        let error = client.place_orders(orders()).await.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::ActionBudgetExhausted { used: 9_999, cap: 10_000, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_action_budget_only_counts_sent_actions() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(502).set_body_string("bad gateway"))
+            .mount(&mock_server)
+            .await;
+
+        let budget = Arc::new(ActionBudget::new(0, 10_000, Decimal::ZERO));
+        let post = |client: HyperLiquidClient| async move {
+            let result: Result<ExchangeResponseData<serde_json::Value>> = client.post_action(dummy_action()).await;
+            result.unwrap_err()
+        };
+
+        let unsigned = HyperLiquidClient::new().with_action_budget(budget.clone());
+        assert!(matches!(post(unsigned).await, HyperLiquidError::SigningError(_)));
+        assert_eq!(budget.snapshot().used, 0, "Actions that cannot be signed are not counted");
+
+        let config = HyperLiquidConfig::new().with_base_url("http://127.0.0.1:1").with_max_retries(0);
+        let unreachable = HyperLiquidClient::with_config(config).unwrap()
+            .with_wallet(Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap())
+            .with_action_budget(budget.clone());
+        assert!(matches!(post(unreachable).await, HyperLiquidError::ConnectionError { .. }));
+        assert_eq!(budget.snapshot().used, 0, "Actions that never reach the exchange are not counted");
+
+        let client = signed_client(&mock_server).await.with_action_budget(budget.clone());
+        assert!(matches!(post(client).await, HyperLiquidError::ServerError { status: 502, .. }));
+        assert_eq!(budget.snapshot().used, 1, "Actions that reach the exchange count even if they fail");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_from_open_orders() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+                "coin": "ETH",
+                "side": "B",
+                "limitPx": "1800.0",
+                "sz": "0.5",
+                "oid": 91490942,
+                "timestamp": 1681247412573u64,
+                "triggerCondition": "N/A",
+                "isTrigger": false,
+                "triggerPx": "0.0",
+                "children": [],
+                "isPositionTpsl": false,
+                "reduceOnly": false,
+                "orderType": "Limit",
+                "origSz": "0.5",
+                "tif": "Gtc",
+                "cloid": "0x00000000000000000000000000000007"
+            }])))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {"type": "cancel", "data": {"statuses": ["success"]}}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = signed_client(&mock_server).await;
+        let open_orders = client
+            .get_frontend_open_orders("0x14791697260e4c9a71f18484c9f997b308e59325")
+            .await
+            .unwrap();
+        let order = &open_orders[0];
+
+        let status = client.cancel_order(4, order.oid).await.unwrap();
+        assert_eq!(status, CancelStatus::Success);
+
+        let cloid = order.cloid.clone().unwrap();
+        let statuses = client
+            .cancel_orders_by_cloid(vec![CancelByCloidRequest::new(4, cloid)])
+            .await
+            .unwrap();
+        assert_eq!(statuses, vec![CancelStatus::Success]);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let cancel: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+        assert_eq!(cancel["action"], json!({"type": "cancel", "cancels": [{"a": 4, "o": 91490942}]}));
+        let cancel_by_cloid: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+        assert_eq!(
+            cancel_by_cloid["action"],
+            json!({"type": "cancelByCloid", "cancels": [{"asset": 4, "cloid": "0x00000000000000000000000000000007"}]})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_requests_resolve_asset_from_meta() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_partial_json(json!({"type": "meta"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "universe": [
+                    {"szDecimals": 5, "name": "BTC", "maxLeverage": 40},
+                    {"szDecimals": 4, "name": "ETH", "maxLeverage": 25}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let open_order = |coin: &str, oid: u64, cloid: Option<&str>| json!({
+            "coin": coin, "side": "B", "limitPx": "1800.0", "sz": "0.5", "oid": oid,
+            "timestamp": 1681247412573u64, "triggerCondition": "N/A", "isTrigger": false,
+            "triggerPx": "0.0", "children": [], "isPositionTpsl": false, "reduceOnly": false,
+            "orderType": "Limit", "origSz": "0.5", "tif": "Gtc", "cloid": cloid
+        });
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_partial_json(json!({"type": "frontendOpenOrders"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                open_order("ETH", 91490942, Some("0x00000000000000000000000000000007")),
+                open_order("ETH", 91490943, None),
+                open_order("DOGE", 91490944, None)
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {"type": "cancel", "data": {"statuses": ["success"]}}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = signed_client(&mock_server).await;
+        let meta = client.get_meta(None).await.unwrap();
+        let open_orders = client
+            .get_frontend_open_orders("0x14791697260e4c9a71f18484c9f997b308e59325")
+            .await
+            .unwrap();
+
+        let cancel = CancelRequest::from_order(&open_orders[0], &meta).unwrap();
+        assert_eq!(cancel, CancelRequest::new(1, 91490942));
+        let cancel_by_cloid = CancelByCloidRequest::from_order(&open_orders[0], &meta).unwrap();
+        assert_eq!(cancel_by_cloid, CancelByCloidRequest::new(1, "0x00000000000000000000000000000007"));
+
+        assert!(matches!(
+            CancelByCloidRequest::from_order(&open_orders[1], &meta),
+            Err(HyperLiquidError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            CancelRequest::from_order(&open_orders[2], &meta),
+            Err(HyperLiquidError::InvalidCoin(_))
+        ));
+
+        assert_eq!(client.cancel_orders(vec![cancel]).await.unwrap(), vec![CancelStatus::Success]);
+        let requests = mock_server.received_requests().await.unwrap();
+        let sent: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+        assert_eq!(sent["action"], json!({"type": "cancel", "cancels": [{"a": 1, "o": 91490942}]}));
+    }
+
+    #[tokio::test]
+    async fn test_modify_and_batch_modify() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .and(wiremock::matchers::body_partial_json(json!({"action": {"type": "modify"}})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {"type": "default"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/exchange"))
+            .and(wiremock::matchers::body_partial_json(json!({"action": {"type": "batchModify"}})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "ok",
+                "response": {
+                    "type": "order",
+                    "data": {"statuses": [{"resting": {"oid": 91490943}}, {"error": "Cannot modify canceled or filled order"}]}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = signed_client(&mock_server).await;
+        let order = OrderRequest::limit(4, true, Decimal::new(1801, 0), Decimal::new(5, 1), Tif::Gtc);
+
+        client.modify_order(91490942u64, order.clone()).await.unwrap();
+
+        let statuses = client
+            .batch_modify_orders(vec![
+                ModifyRequest::new(91490942u64, order.clone()),
+                ModifyRequest::new("0x00000000000000000000000000000007", order),
+            ])
+            .await
+            .unwrap();
+        assert!(matches!(&statuses[0], OrderStatus::Resting(resting) if resting.oid == 91490943));
+        assert!(matches!(&statuses[1], OrderStatus::Error(_)));
+    }
+}
@@ -0,0 +1,87 @@
+use hyperliquid::client::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::HyperLiquidError;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[cfg(test)]
+mod http_config_tests {
+    use super::*;
+
+    async fn mids_server() -> MockServer {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000.0"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    fn config() -> HyperLiquidConfig {
+        HyperLiquidConfig::new().with_max_retries(0).with_timeout(Duration::from_secs(5))
+    }
+
+    fn config_error(result: hyperliquid::errors::Result<HyperLiquidClient>) -> String {
+        match result {
+            Err(HyperLiquidError::ConfigError(message)) => message,
+            Err(other) => panic!("Expected a configuration error, got {:?}", other),
+            Ok(_) => panic!("Expected a configuration error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_are_sent() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(header("x-desk", "arb"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000.0"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = config().with_base_url(mock_server.uri()).with_default_header("X-Desk", "arb");
+        HyperLiquidClient::with_config(config).unwrap().get_all_mids().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_resolve_override_pins_host() {
+        let mock_server = mids_server().await;
+        let address = *mock_server.address();
+
+        // The port comes from the URL
+        let config = config()
+            .with_base_url(format!("http://api.hyperliquid.test:{}", address.port()))
+            .with_resolve("api.hyperliquid.test", address.ip());
+        HyperLiquidClient::with_config(config).unwrap().get_all_mids().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_requests_go_through_proxy() {
+        let proxy = mids_server().await;
+
+        let config = config().with_base_url("http://api.hyperliquid.test").with_proxy(proxy.uri());
+        HyperLiquidClient::with_config(config).unwrap().get_all_mids().await.unwrap();
+    }
+
+    #[test]
+    fn test_invalid_http_settings_are_errors() {
+        let message = config_error(HyperLiquidClient::with_config(config().with_default_header("bad header", "x")));
+        assert!(message.contains("Invalid header 'bad header'"), "{}", message);
+
+        let message = config_error(HyperLiquidClient::with_config(config().with_root_certificate("/nonexistent/ca.pem")));
+        assert!(message.contains("Invalid root certificate /nonexistent/ca.pem"), "{}", message);
+
+        let message = config_error(HyperLiquidClient::with_config(config().with_proxy("not a url")));
+        assert!(message.contains("Invalid proxy 'not a url'"), "{}", message);
+
+        let config = config()
+            .with_http2_prior_knowledge(true)
+            .with_local_address("127.0.0.1".parse().unwrap());
+        assert!(HyperLiquidClient::with_config(config).is_ok());
+    }
+}
@@ -1,5 +1,6 @@
 use hyperliquid::client::HyperLiquidClient;
 use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::HyperLiquidError;
 use serde_json::json;
 use std::time::Duration;
 use wiremock::matchers::{method, path, body_json};
@@ -34,11 +35,11 @@ mod retry_logic_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(3)
             .with_timeout(Duration::from_secs(5));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         // This should succeed after 2 failures + 1 success
         let result = client.get_all_mids().await;
@@ -57,11 +58,11 @@ mod retry_logic_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(2)  // Only 2 retries
             .with_timeout(Duration::from_secs(5));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_err(), "Client should fail after max retries");
@@ -83,11 +84,11 @@ mod retry_logic_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(2) // Shorter for faster testing
             .with_timeout(Duration::from_secs(5));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let start = std::time::Instant::now();
         let _result = client.get_all_mids().await; // Will fail but we're testing timing
@@ -113,18 +114,18 @@ mod retry_logic_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(1)
             .with_timeout(Duration::from_secs(2)); // Short timeout
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_err(), "Should timeout and fail");
         
-        let error_message = result.unwrap_err().to_string();
-        assert!(error_message.contains("retries") || error_message.contains("timeout"), 
-                "Error should mention timeout or retries");
+        let error = result.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::Timeout), 
+                "Error should be a timeout, got {}", error);
     }
 
     #[tokio::test]
@@ -143,10 +144,10 @@ mod retry_logic_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(3);
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_ok(), "Successful request should not retry");
@@ -176,11 +177,11 @@ mod retry_logic_tests {
                 .await;
             
             let config = HyperLiquidConfig::new()
-                .with_base_url(&mock_server.uri())
+                .with_base_url(mock_server.uri())
                 .with_max_retries(2)
                 .with_timeout(Duration::from_secs(5));
             
-            let client = HyperLiquidClient::with_config(config);
+            let client = HyperLiquidClient::with_config(config).unwrap();
             
             let result = client.get_all_mids().await;
             assert!(result.is_err(), "Status {} should result in error", status_code);
@@ -202,14 +203,14 @@ mod retry_logic_tests {
         Mock::given(method("POST"))
             .and(path("/info"))
             .and(body_json(&expected_body))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&json!({})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri());
+            .with_base_url(mock_server.uri());
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_ok(), "Request with correct body should succeed");
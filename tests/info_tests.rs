@@ -0,0 +1,350 @@
+use hyperliquid::client::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::info::clearinghouse_state::Leverage;
+use hyperliquid::info::ledger_updates::LedgerDelta;
+use rust_decimal::Decimal;
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TEST_USER: &str = "0x1234567890123456789012345678901234567890";
+
+#[cfg(test)]
+mod info_tests {
+    use super::*;
+
+    fn test_client(mock_server: &MockServer) -> HyperLiquidClient {
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(0)
+            .with_timeout(Duration::from_secs(5));
+
+        HyperLiquidClient::with_config(config).unwrap()
+    }
+
+    fn meta_json() -> serde_json::Value {
+        json!({
+            "universe": [
+                {"szDecimals": 5, "name": "BTC", "maxLeverage": 40, "marginTableId": 56},
+                {"szDecimals": 4, "name": "ETH", "maxLeverage": 25, "marginTableId": 55}
+            ],
+            "marginTables": [
+                [55, {"description": "", "marginTiers": [{"lowerBound": "0.0", "maxLeverage": 25}]}],
+                [56, {"description": "", "marginTiers": [{"lowerBound": "0.0", "maxLeverage": 40}]}]
+            ],
+            "collateralToken": 0
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_meta() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "meta"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(meta_json()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let meta = test_client(&mock_server).get_meta(None).await.unwrap();
+        assert_eq!(meta.universe.len(), 2);
+        assert_eq!(meta.universe[0].sz_decimals, 5);
+        assert_eq!(meta.asset_id("ETH"), Some(1));
+        assert_eq!(meta.margin_table(55).unwrap().margin_tiers[0].max_leverage, 25);
+    }
+
+    #[tokio::test]
+    async fn test_get_meta_and_asset_ctxs_for_dex() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "metaAndAssetCtxs", "dex": "test"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                meta_json(),
+                [
+                    {
+                        "funding": "0.0000125", "openInterest": "1000.5", "prevDayPx": "49000.0",
+                        "dayNtlVlm": "1000000.0", "premium": "0.0002", "oraclePx": "50000.0",
+                        "markPx": "50010.0", "midPx": "50005.0", "impactPxs": ["50000.0", "50010.0"],
+                        "dayBaseVlm": "20.0"
+                    },
+                    {
+                        "funding": "-0.00001", "openInterest": "0.0", "prevDayPx": "3000.0",
+                        "dayNtlVlm": "0.0", "premium": null, "oraclePx": "3000.0",
+                        "markPx": "3000.0", "midPx": null, "impactPxs": null, "dayBaseVlm": "0.0"
+                    }
+                ]
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let (meta, ctxs) = test_client(&mock_server).get_meta_and_asset_ctxs(Some("test")).await.unwrap();
+        assert_eq!(meta.universe.len(), ctxs.len());
+        assert_eq!(ctxs[0].mark_px, Decimal::new(50010, 0));
+        assert_eq!(ctxs[0].open_interest, Decimal::new(10005, 1));
+        assert_eq!(ctxs[1].funding, Decimal::new(-1, 5));
+        assert!(ctxs[1].mid_px.is_none());
+    }
+
+    fn spot_meta_json() -> serde_json::Value {
+        json!({
+            "tokens": [
+                {"name": "USDC", "szDecimals": 8, "weiDecimals": 8, "index": 0,
+                 "tokenId": "0x6d1e7cde53ba9467b783cb7c530ce054", "isCanonical": true,
+                 "evmContract": null, "fullName": null},
+                {"name": "PURR", "szDecimals": 0, "weiDecimals": 5, "index": 1,
+                 "tokenId": "0xc1fb593aeffbeb02f85e0308e9956a90", "isCanonical": true,
+                 "evmContract": null, "fullName": null},
+                {"name": "HFUN", "szDecimals": 2, "weiDecimals": 8, "index": 2,
+                 "tokenId": "0xbaf265ef389da684513d98d68edf4eae", "isCanonical": false,
+                 "evmContract": null, "fullName": null}
+            ],
+            "universe": [
+                {"name": "PURR/USDC", "tokens": [1, 0], "index": 0, "isCanonical": true},
+                {"name": "@1", "tokens": [2, 0], "index": 1, "isCanonical": false}
+            ]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_spot_meta() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "spotMeta"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(spot_meta_json()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let meta = test_client(&mock_server).get_spot_meta().await.unwrap();
+        let pair = meta.pair("@1").unwrap();
+        let (base, quote) = meta.pair_tokens(pair).unwrap();
+        assert_eq!(base.name, "HFUN");
+        assert_eq!(quote.name, "USDC");
+        assert_eq!(pair.asset_id(), 10_001);
+    }
+
+    #[tokio::test]
+    async fn test_get_spot_meta_and_asset_ctxs() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "spotMetaAndAssetCtxs"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                spot_meta_json(),
+                [
+                    {"dayNtlVlm": "8906.0", "markPx": "0.14", "midPx": "0.209265", "prevDayPx": "0.20432",
+                     "circulatingSupply": "596124229.0", "coin": "PURR/USDC", "totalSupply": "596124229.0",
+                     "dayBaseVlm": "42000.0"},
+                    {"dayNtlVlm": "0.0", "markPx": "5.1", "midPx": null, "prevDayPx": "5.0",
+                     "circulatingSupply": "1000000.0", "coin": "@1"}
+                ]
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let (meta, ctxs) = test_client(&mock_server).get_spot_meta_and_asset_ctxs().await.unwrap();
+        assert_eq!(ctxs.len(), meta.universe.len());
+        assert_eq!(ctxs[0].coin, "PURR/USDC");
+        assert_eq!(ctxs[0].mark_px, Decimal::new(14, 2));
+        assert_eq!(ctxs[1].circulating_supply, Decimal::new(1_000_000, 0));
+        assert!(ctxs[1].mid_px.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_clearinghouse_state() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "clearinghouseState", "user": TEST_USER})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "marginSummary": {"accountValue": "13109.48", "totalNtlPos": "100.03", "totalRawUsd": "13009.45", "totalMarginUsed": "5.0"},
+                "crossMarginSummary": {"accountValue": "13109.48", "totalNtlPos": "100.03", "totalRawUsd": "13009.45", "totalMarginUsed": "5.0"},
+                "crossMaintenanceMarginUsed": "1.25",
+                "withdrawable": "13104.48",
+                "assetPositions": [{
+                    "type": "oneWay",
+                    "position": {
+                        "coin": "ETH", "szi": "0.0335",
+                        "leverage": {"type": "cross", "value": 20},
+                        "entryPx": "2986.3", "positionValue": "100.02765", "unrealizedPnl": "-0.0134",
+                        "returnOnEquity": "-0.0026789", "liquidationPx": "2866.26936529",
+                        "marginUsed": "5.00138", "maxLeverage": 50,
+                        "cumFunding": {"allTime": "514.085417", "sinceOpen": "0.0", "sinceChange": "0.0"}
+                    }
+                }],
+                "time": 1708622398623u64
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = test_client(&mock_server).get_clearinghouse_state(TEST_USER, None).await.unwrap();
+        assert_eq!(state.withdrawable, Decimal::new(1310448, 2));
+
+        let position = &state.asset_positions[0].position;
+        assert_eq!(position.coin, "ETH");
+        assert_eq!(position.leverage, Leverage::Cross { value: 20 });
+        assert_eq!(position.liquidation_px, Some(Decimal::new(286626936529, 8)));
+        assert_eq!(position.cum_funding.all_time, Decimal::new(514085417, 6));
+    }
+
+    #[tokio::test]
+    async fn test_get_spot_clearinghouse_state() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "spotClearinghouseState", "user": TEST_USER})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "balances": [
+                    {"coin": "USDC", "token": 0, "hold": "0.0", "total": "14.625485", "entryNtl": "0.0"},
+                    {"coin": "PURR", "token": 1, "hold": "0", "total": "2000", "entryNtl": "1234.56"}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let state = test_client(&mock_server).get_spot_clearinghouse_state(TEST_USER).await.unwrap();
+        assert_eq!(state.balances.len(), 2);
+        assert_eq!(state.balances[1].token, 1);
+        assert_eq!(state.balances[1].entry_ntl, Decimal::new(123456, 2));
+    }
+
+    #[tokio::test]
+    async fn test_clearinghouse_state_rejects_invalid_user() {
+        let mock_server = MockServer::start().await;
+        let client = test_client(&mock_server);
+
+        assert!(client.get_clearinghouse_state("0x123", Some("test")).await.is_err());
+        assert!(client.get_spot_clearinghouse_state("invalid").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_user_funding() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "userFunding", "user": TEST_USER, "startTime": 1681222254000u64, "endTime": null})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+                "delta": {"coin": "ETH", "fundingRate": "0.0000417", "szi": "49.1477", "type": "funding", "usdc": "-3.625312", "nSamples": null},
+                "hash": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                "time": 1681222254710u64
+            }])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fundings = test_client(&mock_server)
+            .get_user_funding(TEST_USER, 1681222254000, None)
+            .await
+            .unwrap();
+        assert_eq!(fundings[0].delta.coin, "ETH");
+        assert_eq!(fundings[0].delta.usdc, Decimal::new(-3625312, 6));
+        assert_eq!(fundings[0].time, 1681222254710);
+    }
+
+    #[tokio::test]
+    async fn test_get_funding_history() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "fundingHistory", "coin": "ETH", "startTime": 1683849600000u64, "endTime": 1683936000000u64})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"coin": "ETH", "fundingRate": "-0.00022196", "premium": "-0.00052196", "time": 1683849600076u64}
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let history = test_client(&mock_server)
+            .get_funding_history("ETH", 1683849600000, Some(1683936000000))
+            .await
+            .unwrap();
+        assert_eq!(history[0].funding_rate, Decimal::new(-22196, 8));
+        assert_eq!(history[0].premium, Decimal::new(-52196, 8));
+    }
+
+    #[tokio::test]
+    async fn test_get_predicted_fundings() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "predictedFundings"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                ["BTC", [
+                    ["BinPerp", {"fundingRate": "0.0001", "nextFundingTime": 1733961600000u64}],
+                    ["HlPerp", {"fundingRate": "0.0000125", "nextFundingTime": 1733958000000u64}]
+                ]]
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let fundings = test_client(&mock_server).get_predicted_fundings().await.unwrap();
+        let (coin, venues) = &fundings[0];
+        assert_eq!(coin, "BTC");
+        let (venue, predicted) = &venues[1];
+        assert_eq!(venue, "HlPerp");
+        assert_eq!(predicted.as_ref().unwrap().next_funding_time, 1733958000000);
+    }
+
+    #[tokio::test]
+    async fn test_funding_rejects_invalid_input() {
+        let mock_server = MockServer::start().await;
+        let client = test_client(&mock_server);
+
+        assert!(client.get_user_funding("0x123", 0, None).await.is_err());
+        assert!(client.get_user_funding(TEST_USER, 200, Some(100)).await.is_err());
+        assert!(client.get_funding_history("", 0, None).await.is_err());
+        assert!(client.get_funding_history("ETH", 100, Some(100)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_user_non_funding_ledger_updates() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(body_json(json!({"type": "userNonFundingLedgerUpdates", "user": TEST_USER, "startTime": 0, "endTime": null})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"time": 1, "hash": "0x01", "delta": {"type": "deposit", "usdc": "2703997.45"}},
+                {"time": 2, "hash": "0x02", "delta": {
+                    "type": "internalTransfer", "usdc": "1.0", "user": TEST_USER,
+                    "destination": "0x0000000000000000000000000000000000000001", "fee": "0.0"
+                }},
+                {"time": 3, "hash": "0x03", "delta": {
+                    "type": "vaultWithdraw", "vault": "0xdfc24b077bc1425ad1dea75bcb6f8158e10df303", "user": TEST_USER,
+                    "requestedUsd": "100.0", "commission": "1.0", "closingCost": "0.0", "basis": "90.0", "netWithdrawnUsd": "99.0"
+                }},
+                {"time": 4, "hash": "0x04", "delta": {"type": "someFutureDelta", "amount": "1"}}
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let updates = test_client(&mock_server)
+            .get_user_non_funding_ledger_updates(TEST_USER, 0, None)
+            .await
+            .unwrap();
+        assert_eq!(updates.len(), 4);
+        assert!(matches!(updates[0].delta, LedgerDelta::Deposit { usdc } if usdc == Decimal::new(270399745, 2)));
+        assert!(matches!(&updates[1].delta, LedgerDelta::InternalTransfer { user, .. } if user == TEST_USER));
+        assert!(matches!(updates[2].delta, LedgerDelta::VaultWithdraw { net_withdrawn_usd, .. } if net_withdrawn_usd == Decimal::new(99, 0)));
+        assert!(matches!(&updates[3].delta, LedgerDelta::Unknown(raw) if raw["type"] == "someFutureDelta"));
+    }
+}
@@ -1,5 +1,7 @@
 use hyperliquid::client::HyperLiquidClient;
-use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::config::{HyperLiquidConfig, TESTNET_API_URL, TESTNET_WS_URL};
+use hyperliquid::exchange::signing::Wallet;
+use hyperliquid::network::Network;
 use hyperliquid::errors::{validate_ethereum_address, validate_coin_symbol, validate_interval, validate_time_range};
 use std::time::Duration;
 
@@ -20,12 +22,48 @@ mod tests {
             .with_timeout(Duration::from_secs(60))
             .with_max_retries(5);
 
-        let client = HyperLiquidClient::with_config(config.clone());
+        let client = HyperLiquidClient::with_config(config.clone()).unwrap();
         assert_eq!(client.base_url, "https://custom.api.com");
         assert_eq!(client.config.timeout, Duration::from_secs(60));
         assert_eq!(client.config.max_retries, 5);
     }
 
+    #[test]
+    fn test_network_presets() {
+        let config = HyperLiquidConfig::new().with_network(Network::Testnet);
+        assert_eq!((config.base_url.as_str(), config.ws_url.as_str()), (TESTNET_API_URL, TESTNET_WS_URL));
+        assert!(!config.is_mainnet());
+        
+        let config = HyperLiquidConfig::new().with_base_url("https://api.hyperliquid.xyz");
+        assert!(config.is_mainnet());
+        let config = HyperLiquidConfig::new().with_base_url(TESTNET_API_URL);
+        assert_eq!((config.network.clone(), config.ws_url.as_str()), (Network::Testnet, TESTNET_WS_URL));
+        assert!(config.validate_network().is_ok());
+        let config = HyperLiquidConfig::new().with_base_url("http://127.0.0.1:8080");
+        assert!(config.is_mainnet(), "Other URLs keep the configured network");
+        let config = HyperLiquidConfig::new()
+            .with_network(Network::Testnet)
+            .with_base_url("http://127.0.0.1:8080");
+        assert!(!config.is_mainnet());
+    }
+
+    #[test]
+    fn test_signing_refuses_mismatched_network() {
+        let wallet = Wallet::from_private_key("0x0123456789012345678901234567890123456789012345678901234567890123").unwrap();
+        let mut config = HyperLiquidConfig::new();
+        config.base_url = TESTNET_API_URL.to_string();
+        
+        let client = HyperLiquidClient::with_config(config).unwrap().with_wallet(wallet.clone());
+        assert!(client.sign_action(serde_json::json!({"type": "noop"})).is_err());
+        
+        let config = HyperLiquidConfig::new().with_network(Network::Testnet).with_ws_url("wss://api.hyperliquid.xyz/ws");
+        let message = config.validate_network().unwrap_err().to_string();
+        assert!(message.contains("mainnet WebSocket"), "{}", message);
+        
+        let client = HyperLiquidClient::with_config(HyperLiquidConfig::new().with_network(Network::Testnet)).unwrap().with_wallet(wallet);
+        assert!(client.sign_action(serde_json::json!({"type": "noop"})).is_ok());
+    }
+
     #[test]
     fn test_config_from_env() {
         unsafe {
@@ -34,14 +72,31 @@ mod tests {
             std::env::set_var("HYPERLIQUID_MAX_RETRIES", "2");
         }
         
-        let config = HyperLiquidConfig::from_env();
+        let config = HyperLiquidConfig::from_env().unwrap();
         assert_eq!(config.base_url, "https://test.api.com");
         assert_eq!(config.timeout, Duration::from_secs(45));
         assert_eq!(config.max_retries, 2);
+        assert!(config.is_mainnet());
+        
+        // An explicit network keeps its signing behind a custom base URL
+        unsafe {
+            std::env::set_var("HYPERLIQUID_NETWORK", "mainnet");
+        }
+        let config = HyperLiquidConfig::from_env().unwrap();
+        assert_eq!(config.base_url, "https://test.api.com");
+        assert!(config.is_mainnet());
         
-        // Clean up
         unsafe {
             std::env::remove_var("HYPERLIQUID_BASE_URL");
+            std::env::set_var("HYPERLIQUID_NETWORK", "testnet");
+        }
+        let config = HyperLiquidConfig::from_env().unwrap();
+        assert_eq!((config.base_url.as_str(), config.ws_url.as_str()), (TESTNET_API_URL, TESTNET_WS_URL));
+        assert_eq!(config.network, Network::Testnet);
+        
+        // Clean up
+        unsafe {
+            std::env::remove_var("HYPERLIQUID_NETWORK");
             std::env::remove_var("HYPERLIQUID_TIMEOUT_SECS");
             std::env::remove_var("HYPERLIQUID_MAX_RETRIES");
         }
@@ -51,13 +106,15 @@ mod tests {
     fn test_validate_ethereum_address() {
         // Valid addresses
         assert!(validate_ethereum_address("0x1234567890123456789012345678901234567890").is_ok());
-        assert!(validate_ethereum_address("0xabcdefABCDEF1234567890123456789012345678").is_ok());
+        assert!(validate_ethereum_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_ok());
+        assert!(validate_ethereum_address("0X1234567890123456789012345678901234567890").is_ok());
         
         // Invalid addresses
         assert!(validate_ethereum_address("1234567890123456789012345678901234567890").is_err()); // No 0x prefix
         assert!(validate_ethereum_address("0x123456789012345678901234567890123456789").is_err()); // Too short
         assert!(validate_ethereum_address("0x12345678901234567890123456789012345678901").is_err()); // Too long
         assert!(validate_ethereum_address("0x123456789012345678901234567890123456789G").is_err()); // Invalid hex character
+        assert!(validate_ethereum_address("0xabcdefABCDEF1234567890123456789012345678").is_err()); // Bad checksum
         assert!(validate_ethereum_address("").is_err()); // Empty
     }
 
@@ -192,7 +249,7 @@ mod tests {
             .with_max_retries(1)
             .with_timeout(Duration::from_secs(5));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         let result = client.get_all_mids().await;
         
         assert!(result.is_err());
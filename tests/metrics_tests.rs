@@ -0,0 +1,40 @@
+#![cfg(feature = "metrics")]
+
+use hyperliquid::client::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::metrics;
+use hyperliquid::retry::RetryPolicy;
+use hyperliquid::transport::MemoryTransport;
+use serde_json::json;
+use std::time::Duration;
+
+#[cfg(test)]
+mod metrics_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_requests_are_recorded() {
+        let transport = MemoryTransport::new()
+            .with_response("allMids", json!({"BTC": "50000.0"}))
+            .with_status("spotMeta", 200, "not json")
+            .with_status("meta", 429, "slow down");
+        let config = HyperLiquidConfig::new()
+            .with_max_retries(1)
+            .with_retry_policy(RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(1)));
+        let client = HyperLiquidClient::with_config(config).unwrap().with_transport(transport);
+
+        client.get_all_mids().await.unwrap();
+        assert!(client.get_spot_meta().await.is_err());
+        assert!(client.get_meta(None).await.is_err());
+
+        let registry = metrics::registry();
+        assert_eq!(registry.counter("hyperliquid_requests_total", &[("type", "allMids"), ("status", "2xx")]), 1);
+        assert_eq!(registry.counter("hyperliquid_decode_errors_total", &[("type", "spotMeta")]), 1);
+        assert_eq!(registry.counter("hyperliquid_rate_limited_total", &[("type", "meta")]), 2);
+        assert_eq!(registry.counter("hyperliquid_retries_total", &[("type", "meta")]), 1);
+
+        let output = metrics::render();
+        assert!(output.contains("hyperliquid_requests_total{type=\"meta\",status=\"4xx\"} 2\n"));
+        assert!(output.contains("hyperliquid_request_duration_seconds_count{type=\"allMids\"} 1\n"));
+    }
+}
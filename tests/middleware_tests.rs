@@ -0,0 +1,119 @@
+use hyperliquid::client::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::HyperLiquidError;
+use hyperliquid::middleware::{LoggingMiddleware, Middleware, TimingMiddleware};
+use hyperliquid::retry::RetryPolicy;
+use hyperliquid::transport::TransportRequest;
+use serde_json::json;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TEST_USER: &str = "0x1234567890123456789012345678901234567890";
+
+#[derive(Debug)]
+struct ApiKeyHeader;
+
+impl Middleware for ApiKeyHeader {
+    fn before_send(&self, request: &mut TransportRequest) {
+        request.headers.insert("x-api-key", "secret".parse().unwrap());
+    }
+}
+
+#[derive(Debug, Default)]
+struct HookCounter {
+    retries: AtomicU32,
+    errors: AtomicU32,
+}
+
+impl Middleware for HookCounter {
+    fn on_retry(&self, _request: &TransportRequest, _retry: u32, _delay: Duration) {
+        self.retries.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn on_error(&self, _request: &TransportRequest, _error: &HyperLiquidError) {
+        self.errors.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod middleware_tests {
+    use super::*;
+
+    fn config(mock_server: &MockServer) -> HyperLiquidConfig {
+        HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(2)
+            .with_retry_policy(RetryPolicy::new().with_backoff(Duration::from_millis(10), Duration::from_millis(10)))
+    }
+
+    #[tokio::test]
+    async fn test_before_send_adds_headers_on_every_attempt() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(header("x-api-key", "secret"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .and(header("x-api-key", "secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000.0"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let counter = Arc::new(HookCounter::default());
+        let client = HyperLiquidClient::with_config(
+            config(&mock_server).with_middleware(ApiKeyHeader).with_middleware(counter.clone()),
+        ).unwrap();
+
+        client.get_all_mids().await.unwrap();
+        assert_eq!(counter.retries.load(Ordering::SeqCst), 1);
+        assert_eq!(counter.errors.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_logging_and_timing_see_retries_and_failure() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("maintenance"))
+            .mount(&mock_server)
+            .await;
+
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let sink = lines.clone();
+        let logging = LoggingMiddleware::new()
+            .with_bodies(true)
+            .with_sink(move |line| sink.lock().unwrap().push(line.to_string()));
+        let timing = Arc::new(TimingMiddleware::new());
+        let counter = Arc::new(HookCounter::default());
+        let client = HyperLiquidClient::with_config(
+            config(&mock_server)
+                .with_middleware(logging)
+                .with_middleware(timing.clone())
+                .with_middleware(counter.clone()),
+        ).unwrap();
+
+        let result = client.get_open_orders(TEST_USER).await;
+        assert!(matches!(result, Err(HyperLiquidError::ServerError { retries: 2, .. })));
+
+        let lines = lines.lock().unwrap();
+        assert_eq!(lines.iter().filter(|line| line.starts_with("--> POST /info openOrders")).count(), 3);
+        assert_eq!(lines.iter().filter(|line| line.starts_with("<-- 503 /info openOrders")).count(), 3);
+        assert_eq!(lines.iter().filter(|line| line.starts_with("retry ")).count(), 2);
+        assert!(lines.last().unwrap().starts_with("openOrders failed: Server error after 2 retries"));
+        assert!(lines[0].contains("0x1234…7890") && !lines[0].contains(TEST_USER), "Addresses should be redacted");
+
+        assert_eq!(timing.stats()["openOrders"].count, 3);
+        assert_eq!(counter.errors.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,5 +1,8 @@
 use hyperliquid::client::HyperLiquidClient;
 use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::HyperLiquidError;
+use hyperliquid::rate_limiter::RateLimiter;
+use hyperliquid::retry::RetryPolicy;
 use serde_json::json;
 use std::time::Duration;
 use wiremock::matchers::{method, path};
@@ -37,19 +40,19 @@ mod rate_limit_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(3)
             .with_timeout(Duration::from_secs(10));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let start = std::time::Instant::now();
         let result = client.get_all_mids().await;
         let elapsed = start.elapsed();
         
         assert!(result.is_ok(), "Should succeed after rate limit backoff");
-        // Our implementation uses exponential backoff (1 second for first retry), not Retry-After header
-        assert!(elapsed >= Duration::from_secs(1), "Should use exponential backoff for rate limits");
+        // Retry-After takes precedence over the 1 second exponential backoff
+        assert!(elapsed >= Duration::from_secs(2), "Should wait as long as Retry-After asks");
     }
 
     #[tokio::test]
@@ -76,11 +79,11 @@ mod rate_limit_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(3)
             .with_timeout(Duration::from_secs(10));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let start = std::time::Instant::now();
         let result = client.get_all_mids().await;
@@ -118,11 +121,11 @@ mod rate_limit_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(3)
             .with_timeout(Duration::from_secs(15));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let start = std::time::Instant::now();
         let result = client.get_all_mids().await;
@@ -148,11 +151,11 @@ mod rate_limit_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(2) // Only 2 retries
             .with_timeout(Duration::from_secs(10));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_all_mids().await;
         assert!(result.is_err(), "Should fail after exhausting retries on rate limits");
@@ -191,11 +194,11 @@ mod rate_limit_tests {
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(3)
             .with_timeout(Duration::from_secs(10));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let start = std::time::Instant::now();
         let result = client.get_all_mids().await;
@@ -220,16 +223,16 @@ mod rate_limit_tests {
         
         Mock::given(method("POST"))
             .and(path("/info"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&json!({"test": "success"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000.0"})))
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(2)
             .with_timeout(Duration::from_secs(15));
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         // Make 5 concurrent requests
         let mut handles = vec![];
@@ -272,18 +275,206 @@ mod rate_limit_tests {
         Mock::given(method("POST"))
             .and(path("/info"))
             .and(wiremock::matchers::body_json(&expected_body))
-            .respond_with(ResponseTemplate::new(200).set_body_json(&json!([])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
             .mount(&mock_server)
             .await;
         
         let config = HyperLiquidConfig::new()
-            .with_base_url(&mock_server.uri())
+            .with_base_url(mock_server.uri())
             .with_max_retries(3);
         
-        let client = HyperLiquidClient::with_config(config);
+        let client = HyperLiquidClient::with_config(config).unwrap();
         
         let result = client.get_open_orders("0x1234567890123456789012345678901234567890").await;
         assert!(result.is_ok(), "Should succeed with correct request body preserved");
     }
-}
 
+    #[tokio::test]
+    async fn test_retry_after_http_date_overrides_backoff() {
+        let mock_server = MockServer::start().await;
+
+        // An HTTP date in the past means "retry now", well before the 5 second backoff
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(
+                ResponseTemplate::new(503)
+                    .insert_header("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT")
+            )
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000.0"})))
+            .mount(&mock_server)
+            .await;
+
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(1)
+            .with_retry_policy(RetryPolicy::new().with_backoff(Duration::from_secs(5), Duration::from_secs(5)));
+
+        let client = HyperLiquidClient::with_config(config).unwrap();
+
+        let start = std::time::Instant::now();
+        let result = client.get_all_mids().await;
+
+        assert!(result.is_ok(), "Should succeed after honoring Retry-After");
+        assert!(start.elapsed() < Duration::from_secs(2), "Should not fall back to the configured backoff");
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_status_fails_immediately() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(3)
+            .with_retry_policy(RetryPolicy::new().with_retryable_status(503, false));
+
+        let client = HyperLiquidClient::with_config(config).unwrap();
+
+        let error = client.get_all_mids().await.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::ServerError { status: 503, retries: 0, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_deadline_stops_retrying() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(429))
+            .mount(&mock_server)
+            .await;
+
+        // Ten 200ms retries would take 2 seconds; the deadline cuts them short
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(10)
+            .with_retry_policy(
+                RetryPolicy::new()
+                    .with_backoff(Duration::from_millis(200), Duration::from_millis(200))
+                    .with_deadline(Some(Duration::from_millis(500)))
+            );
+
+        let client = HyperLiquidClient::with_config(config).unwrap();
+
+        let start = std::time::Instant::now();
+        let error = client.get_all_mids().await.unwrap_err();
+
+        assert!(start.elapsed() < Duration::from_secs(1), "Should give up at the deadline");
+        assert!(matches!(error, HyperLiquidError::RateLimitExceeded { retries } if retries < 10));
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_max_delay_is_not_retried_early() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "60"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(3)
+            .with_retry_policy(RetryPolicy::new().with_backoff(Duration::from_millis(100), Duration::from_secs(1)));
+
+        let client = HyperLiquidClient::with_config(config).unwrap();
+
+        let start = std::time::Instant::now();
+        let error = client.get_all_mids().await.unwrap_err();
+
+        assert!(start.elapsed() < Duration::from_millis(500), "Should not wait out a capped Retry-After");
+        assert!(matches!(error, HyperLiquidError::RateLimitExceeded { retries: 0 }));
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_past_deadline_is_not_retried() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "5"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_max_retries(3)
+            .with_retry_policy(RetryPolicy::new().with_deadline(Some(Duration::from_secs(2))));
+
+        let client = HyperLiquidClient::with_config(config).unwrap();
+
+        let start = std::time::Instant::now();
+        let error = client.get_all_mids().await.unwrap_err();
+
+        assert!(start.elapsed() < Duration::from_millis(500), "Should give up instead of waiting past the deadline");
+        assert!(matches!(error, HyperLiquidError::RateLimitExceeded { retries: 0 }));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_throttles_across_clones() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"BTC": "50000.0"})))
+            .mount(&mock_server)
+            .await;
+
+        // Room for two allMids requests (weight 2 each) per 400ms
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_rate_limiter(RateLimiter::with_period(4, Duration::from_millis(400)));
+
+        let client = HyperLiquidClient::with_config(config).unwrap();
+        let clone = client.clone();
+
+        let start = std::time::Instant::now();
+        client.get_all_mids().await.unwrap();
+        clone.get_all_mids().await.unwrap();
+        assert!(start.elapsed() < Duration::from_millis(150), "A full bucket should not throttle");
+
+        clone.get_all_mids().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(180), "Clones should share the budget");
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_charges_per_item_weight() {
+        let mock_server = MockServer::start().await;
+
+        let fills: Vec<_> = (0..40).map(|_| json!({})).collect();
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(fills))
+            .mount(&mock_server)
+            .await;
+
+        let limiter = std::sync::Arc::new(RateLimiter::with_period(100, Duration::from_secs(3600)));
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_shared_rate_limiter(limiter.clone());
+
+        let client = HyperLiquidClient::with_config(config).unwrap();
+        let _: Vec<serde_json::Value> = client
+            .make_user_request("userFills", "0x1234567890123456789012345678901234567890")
+            .await
+            .unwrap();
+
+        // 20 for the request plus 1 per 20 returned fills
+        assert!((limiter.available() - 78.0).abs() < 0.5, "available: {}", limiter.available());
+    }
+}
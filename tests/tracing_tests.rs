@@ -0,0 +1,110 @@
+#![cfg(feature = "tracing")]
+
+use hyperliquid::client::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::retry::RetryPolicy;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TEST_USER: &str = "0x1234567890123456789012345678901234567890";
+
+type Fields = HashMap<String, String>;
+
+/// Subscriber keeping the name and recorded fields of every span of this crate
+#[derive(Default)]
+struct SpanRecorder {
+    next_id: AtomicU64,
+    spans: Arc<Mutex<Vec<(&'static str, Fields)>>>,
+}
+
+struct FieldVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.insert(field.name().to_string(), format!("{:?}", value).trim_matches('"').to_string());
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target().starts_with("hyperliquid")
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        let mut fields = Fields::new();
+        attributes.record(&mut FieldVisitor(&mut fields));
+        self.spans.lock().unwrap().push((attributes.metadata().name(), fields));
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut FieldVisitor(&mut spans[span.into_u64() as usize - 1].1));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[cfg(test)]
+mod tracing_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_spans_per_request_and_attempt() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let recorder = SpanRecorder::default();
+        let spans = recorder.spans.clone();
+        let _guard = tracing::subscriber::set_default(recorder);
+
+        let config = HyperLiquidConfig::new()
+            .with_base_url(mock_server.uri())
+            .with_retry_policy(RetryPolicy::new().with_backoff(Duration::from_millis(10), Duration::from_millis(10)))
+            .with_trace_hashed_users(true);
+        let client = HyperLiquidClient::with_config(config).unwrap();
+        client.get_open_orders(TEST_USER).await.unwrap();
+
+        let spans = spans.lock().unwrap();
+        let names: Vec<_> = spans.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["hyperliquid.request", "hyperliquid.attempt", "hyperliquid.attempt"]);
+
+        let request = &spans[0].1;
+        assert_eq!(request["request_type"], "openOrders");
+        assert_eq!(request["path"], "/info");
+        assert_eq!(request["user"].len(), 16, "User should be hashed");
+        assert_ne!(request["user"], TEST_USER);
+
+        let (first, second) = (&spans[1].1, &spans[2].1);
+        assert_eq!((first["attempt"].as_str(), first["status"].as_str()), ("0", "500"));
+        assert_eq!(first["backoff_ms"], "10");
+        assert_eq!((second["attempt"].as_str(), second["status"].as_str()), ("1", "200"));
+        assert_eq!(second["response_bytes"], "2");
+    }
+}
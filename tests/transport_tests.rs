@@ -0,0 +1,142 @@
+use hyperliquid::address::Address;
+use hyperliquid::client::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::HyperLiquidError;
+use hyperliquid::exchange::order::{OrderRequest, OrderStatus, Tif};
+use hyperliquid::exchange::signing::Wallet;
+use hyperliquid::recording::{RecordingTransport, ReplayTransport};
+use hyperliquid::transport::MemoryTransport;
+use rust_decimal::Decimal;
+use serde_json::json;
+use std::sync::Arc;
+
+const TEST_PRIVATE_KEY: &str = "0x0123456789012345678901234567890123456789012345678901234567890123";
+
+#[cfg(test)]
+mod transport_tests {
+    use super::*;
+
+    fn client(transport: Arc<MemoryTransport>) -> HyperLiquidClient {
+        HyperLiquidClient::with_config(HyperLiquidConfig::new().with_max_retries(0)).unwrap().with_transport(transport)
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_serves_info_requests() {
+        let transport = Arc::new(
+            MemoryTransport::new()
+                .with_response("allMids", json!({"BTC": "50000.0"}))
+                .with_response("l2Book", json!({"coin": "BTC", "time": 1, "levels": [[], []]})),
+        );
+        let client = client(transport.clone());
+
+        let mids = client.get_all_mids().await.unwrap();
+        assert_eq!(mids.prices["BTC"].0, Decimal::new(50_000, 0));
+        client.get_l2_book("BTC").await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].url.ends_with("/info"));
+        assert_eq!(requests[1].request_type().as_deref(), Some("l2Book"));
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_serves_exchange_actions() {
+        let transport = Arc::new(MemoryTransport::new().with_response(
+            "order",
+            json!({"status": "ok", "response": {"type": "order", "data": {"statuses": [{"resting": {"oid": 7}}]}}}),
+        ));
+        let client = client(transport.clone()).with_wallet(Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap());
+
+        let order = OrderRequest::limit(0, true, Decimal::new(50_000, 0), Decimal::new(1, 2), Tif::Gtc);
+        let status = client.place_order(order).await.unwrap();
+
+        assert!(matches!(status, OrderStatus::Resting(resting) if resting.oid == 7));
+        assert_eq!(transport.requests()[0].path(), "/exchange");
+    }
+
+    #[tokio::test]
+    async fn test_memory_transport_statuses_map_to_errors() {
+        let transport = Arc::new(MemoryTransport::new().with_status("allMids", 503, "maintenance"));
+        let client = client(transport);
+
+        let error = client.get_all_mids().await.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::ServerError { status: 503, ref message, retries: 0 } if message == "maintenance"));
+
+        let error = client.get_meta(None).await.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::ApiError { status: 422, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_recorded_session_replays_without_transport() {
+        let dir = std::env::temp_dir().join(format!("hyperliquid-replay-{}", std::process::id()));
+        let recorder = RecordingTransport::new(
+            MemoryTransport::new()
+                .with_response("allMids", json!({"BTC": "50000.0"}))
+                .with_response("l2Book", json!({"coin": "BTC", "time": 1, "levels": [[], []]})),
+            &dir,
+        );
+        let recording = HyperLiquidClient::new().with_transport(recorder);
+        recording.get_all_mids().await.unwrap();
+        recording.get_l2_book("BTC").await.unwrap();
+
+        let replay = HyperLiquidClient::with_config(HyperLiquidConfig::new().with_max_retries(0)).unwrap()
+            .with_transport(ReplayTransport::new(&dir));
+        let mids = replay.get_all_mids().await.unwrap();
+        assert_eq!(mids.prices["BTC"].0, Decimal::new(50_000, 0));
+        assert_eq!(replay.get_l2_book("BTC").await.unwrap().coin, "BTC");
+
+        let error = replay.get_l2_book("ETH").await.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::ApiError { status: 404, .. }));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_recording_failure_keeps_response() {
+        // A file where the fixture directory should be makes every write fail
+        let blocker = std::env::temp_dir().join(format!("hyperliquid-record-blocker-{}", std::process::id()));
+        std::fs::write(&blocker, "").unwrap();
+
+        let inner = Arc::new(MemoryTransport::new().with_response("allMids", json!({"BTC": "50000.0"})));
+        let failures = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = failures.clone();
+        let recorder = RecordingTransport::new(inner.clone(), blocker.join("fixtures"))
+            .with_write_error_hook(move |_, _| {
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            });
+
+        let client = HyperLiquidClient::with_config(HyperLiquidConfig::new().with_max_retries(2)).unwrap().with_transport(recorder);
+        let mids = client.get_all_mids().await.unwrap();
+        assert_eq!(mids.prices["BTC"].0, Decimal::new(50_000, 0));
+        assert_eq!(inner.requests().len(), 1);
+        assert_eq!(failures.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        std::fs::remove_file(blocker).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_addresses_compare_across_endpoints() {
+        let validator = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let transport = Arc::new(
+            MemoryTransport::new()
+                .with_response("delegations", json!([
+                    {"validator": validator.to_lowercase(), "amount": "10.0", "lockedUntilTimestamp": 1}
+                ]))
+                .with_response("userFees", json!({})),
+        );
+        let client = client(transport.clone());
+
+        // Any casing of the same address is accepted and sent in canonical lowercase form
+        let delegations = client.get_delegations(validator.to_uppercase().replacen('X', "x", 1)).await.unwrap();
+        assert_eq!(delegations[0].validator, validator.parse::<Address>().unwrap());
+        assert_eq!(delegations[0].validator, validator);
+        assert!(transport.requests()[0].body.contains(&validator.to_lowercase()));
+
+        let by_address = client.get_delegations(delegations[0].validator).await.unwrap();
+        assert_eq!(by_address[0].validator, delegations[0].validator);
+
+        let error = client.get_user_fees("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed").await.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::InvalidAddress(message) if message.contains("checksum")));
+        assert_eq!(transport.requests().len(), 2);
+    }
+}
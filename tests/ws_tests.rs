@@ -0,0 +1,715 @@
+use futures::{SinkExt, StreamExt};
+use hyperliquid::action_budget::ActionBudget;
+use hyperliquid::client::HyperLiquidClient;
+use hyperliquid::config::HyperLiquidConfig;
+use hyperliquid::errors::HyperLiquidError;
+use hyperliquid::exchange::action::ExchangeResponseData;
+use hyperliquid::exchange::cancel::{BulkCancel, CancelRequest, CancelStatuses};
+use hyperliquid::exchange::signing::Wallet;
+use hyperliquid::info::l2_book::{L2BookRequest, L2BookResponse};
+use hyperliquid::ws::client::WsClient;
+use hyperliquid::ws::message::{WsMessage, WsUserEvent};
+use hyperliquid::ws::subscription::Subscription;
+use rust_decimal::Decimal;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message;
+
+const TEST_USER: &str = "0x1234567890123456789012345678901234567890";
+const TEST_PRIVATE_KEY: &str = "0x0123456789012345678901234567890123456789012345678901234567890123";
+
+/// Local stand-in for the HyperLiquid WebSocket server
+///
+/// Every text frame received from the client is forwarded to the returned receiver, and
+/// every string pushed into the returned sender is written to the client.
+async fn start_server() -> (String, mpsc::UnboundedReceiver<serde_json::Value>, mpsc::UnboundedSender<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let (received_tx, received_rx) = mpsc::unbounded_channel();
+    let (push_tx, mut push_rx) = mpsc::unbounded_channel::<String>();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+        loop {
+            tokio::select! {
+                frame = socket.next() => match frame {
+                    Some(Ok(Message::Text(text))) => {
+                        let _ = received_tx.send(serde_json::from_str(&text).unwrap());
+                    }
+                    Some(Ok(_)) => {}
+                    _ => return,
+                },
+                push = push_rx.recv() => match push {
+                    Some(text) => socket.send(Message::Text(text)).await.unwrap(),
+                    None => return,
+                },
+            }
+        }
+    });
+
+    (url, received_rx, push_tx)
+}
+
+/// A single accepted connection of [`start_reconnecting_server`]
+struct ServerConnection {
+    received: mpsc::UnboundedReceiver<serde_json::Value>,
+    push: mpsc::UnboundedSender<String>,
+}
+
+/// Like [`start_server`], but keeps accepting connections
+///
+/// Each accepted connection is handed out separately; dropping its `push` sender closes it.
+async fn start_reconnecting_server() -> (String, mpsc::UnboundedReceiver<ServerConnection>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let (connections_tx, connections_rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let Ok(mut socket) = tokio_tungstenite::accept_async(stream).await else {
+                continue;
+            };
+            let (received_tx, received_rx) = mpsc::unbounded_channel();
+            let (push_tx, mut push_rx) = mpsc::unbounded_channel::<String>();
+            if connections_tx.send(ServerConnection { received: received_rx, push: push_tx }).is_err() {
+                return;
+            }
+
+            tokio::spawn(async move {
+                loop {
+                    tokio::select! {
+                        frame = socket.next() => match frame {
+                            Some(Ok(Message::Text(text))) => {
+                                let _ = received_tx.send(serde_json::from_str(&text).unwrap());
+                            }
+                            Some(Ok(_)) => {}
+                            _ => return,
+                        },
+                        push = push_rx.recv() => match push {
+                            Some(text) => socket.send(Message::Text(text)).await.unwrap(),
+                            None => {
+                                let _ = socket.close(None).await;
+                                return;
+                            }
+                        },
+                    }
+                }
+            });
+        }
+    });
+
+    (url, connections_rx)
+}
+
+/// Minimal HTTP proxy that tunnels one `CONNECT` to the requested address
+///
+/// The request head is forwarded to the returned receiver.
+async fn start_connect_proxy() -> (String, mpsc::UnboundedReceiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (heads_tx, heads_rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let (mut client, _) = listener.accept().await.unwrap();
+        let mut head = Vec::new();
+        while !head.ends_with(b"\r\n\r\n") {
+            head.push(client.read_u8().await.unwrap());
+        }
+        let head = String::from_utf8(head).unwrap();
+        let target = head.split_whitespace().nth(1).unwrap().to_string();
+        let _ = heads_tx.send(head);
+
+        let mut server = TcpStream::connect(target).await.unwrap();
+        client.write_all(b"HTTP/1.1 200 Connection established\r\n\r\n").await.unwrap();
+        let _ = tokio::io::copy_bidirectional(&mut client, &mut server).await;
+    });
+
+    (url, heads_rx)
+}
+
+/// Minimal SOCKS5 proxy that tunnels one connection to `127.0.0.1:port`
+///
+/// The requested domain is forwarded to the returned receiver.
+async fn start_socks_proxy() -> (String, mpsc::UnboundedReceiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("socks5h://{}", listener.local_addr().unwrap());
+    let (domains_tx, domains_rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        let (mut client, _) = listener.accept().await.unwrap();
+        // Greeting: version, method count, methods; accept without authentication
+        let mut greeting = [0u8; 2];
+        client.read_exact(&mut greeting).await.unwrap();
+        let mut methods = vec![0u8; greeting[1] as usize];
+        client.read_exact(&mut methods).await.unwrap();
+        client.write_all(&[5, 0]).await.unwrap();
+
+        // Request: version, CONNECT, reserved, domain name address type
+        let mut request = [0u8; 4];
+        client.read_exact(&mut request).await.unwrap();
+        assert_eq!(request, [5, 1, 0, 3]);
+        let mut domain = vec![0u8; client.read_u8().await.unwrap() as usize];
+        client.read_exact(&mut domain).await.unwrap();
+        let port = client.read_u16().await.unwrap();
+        let _ = domains_tx.send(String::from_utf8(domain).unwrap());
+
+        let mut server = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        client.write_all(&[5, 0, 0, 1, 0, 0, 0, 0, 0, 0]).await.unwrap();
+        let _ = tokio::io::copy_bidirectional(&mut client, &mut server).await;
+    });
+
+    (url, domains_rx)
+}
+
+fn fast_reconnect_config(url: &str) -> HyperLiquidConfig {
+    HyperLiquidConfig::default()
+        .with_ws_url(url)
+        .with_ws_reconnect_backoff(Duration::from_millis(10), Duration::from_millis(50))
+}
+
+async fn next_message(ws: &mut WsClient) -> WsMessage {
+    tokio::time::timeout(Duration::from_secs(5), ws.next_message())
+        .await
+        .expect("timed out waiting for message")
+        .expect("connection closed")
+}
+
+#[cfg(test)]
+mod ws_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_subscribe_and_receive_market_data() {
+        let (url, mut received, push) = start_server().await;
+        let mut ws = WsClient::connect(&url).await.unwrap();
+
+        ws.subscribe(Subscription::l2_book("BTC")).unwrap();
+        let request = received.recv().await.unwrap();
+        assert_eq!(
+            request,
+            json!({"method": "subscribe", "subscription": {"type": "l2Book", "coin": "BTC"}})
+        );
+
+        push.send(json!({
+            "channel": "subscriptionResponse",
+            "data": {"method": "subscribe", "subscription": {"type": "l2Book", "coin": "BTC"}}
+        }).to_string()).unwrap();
+        push.send(json!({
+            "channel": "l2Book",
+            "data": {
+                "coin": "BTC",
+                "time": 1700000000000u64,
+                "levels": [
+                    [{"px": "50000", "sz": "1.5", "n": 3}],
+                    [{"px": "50001", "sz": "0.5", "n": 1}]
+                ]
+            }
+        }).to_string()).unwrap();
+        push.send(json!({
+            "channel": "candle",
+            "data": {
+                "t": 1700000000000u64, "T": 1700000059999u64, "s": "BTC", "i": "1m",
+                "o": "50000", "c": "50010", "h": "50020", "l": "49990", "v": "12.5", "n": 42
+            }
+        }).to_string()).unwrap();
+
+        assert!(matches!(next_message(&mut ws).await, WsMessage::SubscriptionResponse(r) if r.method == "subscribe"));
+        match next_message(&mut ws).await {
+            WsMessage::L2Book(book) => {
+                assert_eq!(book.coin, "BTC");
+                assert_eq!(book.levels[0][0].n, 3);
+            }
+            other => panic!("Expected l2Book, got {:?}", other),
+        }
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Candle(candle) if candle.n == 42));
+    }
+
+    #[tokio::test]
+    async fn test_user_subscriptions() {
+        let (url, mut received, push) = start_server().await;
+        let mut ws = WsClient::connect(&url).await.unwrap();
+
+        ws.subscribe(Subscription::user_fills(TEST_USER).unwrap()).unwrap();
+        ws.subscribe(Subscription::user_events(TEST_USER).unwrap()).unwrap();
+        assert_eq!(received.recv().await.unwrap()["subscription"]["type"], "userFills");
+        assert_eq!(received.recv().await.unwrap()["subscription"]["type"], "userEvents");
+        assert_eq!(ws.subscriptions().len(), 2);
+
+        push.send(json!({
+            "channel": "userFills",
+            "data": {
+                "isSnapshot": true,
+                "user": TEST_USER,
+                "fills": [{
+                    "coin": "ETH", "px": "3000", "sz": "0.1", "side": "B", "time": 1700000000000u64,
+                    "startPosition": "0", "dir": "Open Long", "closedPnl": "0", "hash": "0xabc",
+                    "oid": 1, "crossed": true, "fee": "0.1", "tid": 2, "feeToken": "USDC"
+                }]
+            }
+        }).to_string()).unwrap();
+        push.send(json!({
+            "channel": "user",
+            "data": {"nonUserCancel": [{"coin": "ETH", "oid": 1}]}
+        }).to_string()).unwrap();
+
+        match next_message(&mut ws).await {
+            WsMessage::UserFills(fills) => {
+                assert!(fills.is_snapshot);
+                assert_eq!(fills.fills[0].coin, "ETH");
+            }
+            other => panic!("Expected userFills, got {:?}", other),
+        }
+        assert!(matches!(next_message(&mut ws).await, WsMessage::UserEvents(WsUserEvent::NonUserCancel(_))));
+
+        ws.unsubscribe(&Subscription::user_events(TEST_USER).unwrap()).unwrap();
+        let request = received.recv().await.unwrap();
+        assert_eq!(request["method"], "unsubscribe");
+        assert_eq!(ws.subscriptions(), vec![Subscription::user_fills(TEST_USER).unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_subscription_rejected() {
+        let (url, _received, _push) = start_server().await;
+        let ws = WsClient::connect(&url).await.unwrap();
+
+        assert!(ws.subscribe(Subscription::trades("")).is_err());
+        assert!(Subscription::order_updates("0x123").is_err());
+        assert!(ws.subscriptions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_connection_closed_by_server() {
+        let (url, _received, push) = start_server().await;
+        let config = fast_reconnect_config(&url).with_ws_max_reconnect_attempts(Some(2));
+        let mut ws = WsClient::connect_with_config(config).await.unwrap();
+
+        // The stand-in server only accepts one connection, so reconnecting gives up
+        drop(push);
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Gap { .. }));
+        let message = tokio::time::timeout(Duration::from_secs(5), ws.next_message()).await.unwrap();
+        assert!(message.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_subscriptions() {
+        let (url, mut connections) = start_reconnecting_server().await;
+        let mut ws = WsClient::connect_with_config(fast_reconnect_config(&url)).await.unwrap();
+        let mut first = connections.recv().await.unwrap();
+
+        ws.subscribe(Subscription::l2_book("BTC")).unwrap();
+        ws.subscribe(Subscription::user_fills(TEST_USER).unwrap()).unwrap();
+        first.received.recv().await.unwrap();
+        first.received.recv().await.unwrap();
+
+        drop(first.push);
+        match next_message(&mut ws).await {
+            WsMessage::Gap { reason } => assert!(reason.contains("closed"), "{}", reason),
+            other => panic!("Expected gap, got {:?}", other),
+        }
+
+        let mut second = tokio::time::timeout(Duration::from_secs(5), connections.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let mut replayed = vec![
+            second.received.recv().await.unwrap()["subscription"]["type"].clone(),
+            second.received.recv().await.unwrap()["subscription"]["type"].clone(),
+        ];
+        replayed.sort_by_key(|value| value.to_string());
+        assert_eq!(replayed, vec![json!("l2Book"), json!("userFills")]);
+
+        match next_message(&mut ws).await {
+            WsMessage::Reconnected { attempts, .. } => assert!(attempts >= 1),
+            other => panic!("Expected reconnected, got {:?}", other),
+        }
+
+        second.push.send(json!({
+            "channel": "userFills",
+            "data": {"isSnapshot": true, "user": TEST_USER, "fills": []}
+        }).to_string()).unwrap();
+        assert!(matches!(next_message(&mut ws).await, WsMessage::UserFills(fills) if fills.is_snapshot));
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_ping() {
+        let (url, mut received, push) = start_server().await;
+        let config = HyperLiquidConfig::default()
+            .with_ws_url(&url)
+            .with_ws_ping_interval(Duration::from_millis(50));
+        let mut ws = WsClient::connect_with_config(config).await.unwrap();
+
+        let request = tokio::time::timeout(Duration::from_secs(5), received.recv()).await.unwrap().unwrap();
+        assert_eq!(request, json!({"method": "ping"}));
+
+        push.send(json!({"channel": "pong"}).to_string()).unwrap();
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Pong));
+    }
+
+    #[tokio::test]
+    async fn test_stale_connection_reconnects() {
+        let (url, mut connections) = start_reconnecting_server().await;
+        let config = fast_reconnect_config(&url)
+            .with_ws_ping_interval(Duration::from_secs(60))
+            .with_ws_stale_timeout(Duration::from_millis(100));
+        let mut ws = WsClient::connect_with_config(config).await.unwrap();
+        let _first = connections.recv().await.unwrap();
+
+        match next_message(&mut ws).await {
+            WsMessage::Gap { reason } => assert!(reason.contains("No message received"), "{}", reason),
+            other => panic!("Expected gap, got {:?}", other),
+        }
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Reconnected { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_connect_failure() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        assert!(WsClient::connect(&url).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_post_info() {
+        let (url, mut received, push) = start_server().await;
+        let ws = WsClient::connect(&url).await.unwrap();
+
+        let responder = tokio::spawn(async move {
+            let request = received.recv().await.unwrap();
+            assert_eq!(request["method"], "post");
+            assert_eq!(request["request"]["type"], "info");
+            assert_eq!(request["request"]["payload"]["type"], "l2Book");
+            assert_eq!(request["request"]["payload"]["coin"], "ETH");
+
+            push.send(json!({
+                "channel": "post",
+                "data": {
+                    "id": request["id"],
+                    "response": {
+                        "type": "info",
+                        "payload": {
+                            "type": "l2Book",
+                            "data": {"coin": "ETH", "time": 1, "levels": [[{"px": "3000", "sz": "2", "n": 1}], []]}
+                        }
+                    }
+                }
+            }).to_string()).unwrap();
+            push
+        });
+
+        let book: L2BookResponse = ws.post_info(&L2BookRequest::new("ETH")).await.unwrap();
+        assert_eq!(book.coin, "ETH");
+        assert_eq!(book.levels[0][0].n, 1);
+        responder.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_post_action() {
+        let (url, mut received, push) = start_server().await;
+        let ws = WsClient::connect(&url).await.unwrap();
+        let client = HyperLiquidClient::new().with_wallet(Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap());
+
+        let responder = tokio::spawn(async move {
+            let request = received.recv().await.unwrap();
+            assert_eq!(request["request"]["type"], "action");
+            assert_eq!(request["request"]["payload"]["action"]["type"], "cancel");
+            assert!(request["request"]["payload"]["signature"]["r"].is_string());
+
+            push.send(json!({
+                "channel": "post",
+                "data": {
+                    "id": request["id"],
+                    "response": {
+                        "type": "action",
+                        "payload": {
+                            "status": "ok",
+                            "response": {"type": "cancel", "data": {"statuses": ["success"]}}
+                        }
+                    }
+                }
+            }).to_string()).unwrap();
+            push
+        });
+
+        let action = BulkCancel::new(&[CancelRequest::new(0, 42)]).unwrap();
+        let signed = client.sign_action(action).unwrap();
+        let response: ExchangeResponseData<CancelStatuses> = ws.post_action(&signed).await.unwrap();
+        assert_eq!(response.data.unwrap().statuses.len(), 1);
+        responder.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_post_action_counts_against_action_budget() {
+        let (url, mut received, push) = start_server().await;
+        let budget = Arc::new(ActionBudget::new(9_999, 10_000, Decimal::ZERO));
+        let ws = WsClient::connect(&url).await.unwrap().with_action_budget(budget.clone());
+        let client = HyperLiquidClient::new().with_wallet(Wallet::from_private_key(TEST_PRIVATE_KEY).unwrap());
+
+        let responder = tokio::spawn(async move {
+            let request = received.recv().await.unwrap();
+            push.send(json!({
+                "channel": "post",
+                "data": {
+                    "id": request["id"],
+                    "response": {
+                        "type": "action",
+                        "payload": {"status": "ok", "response": {"type": "cancel", "data": {"statuses": ["success"]}}}
+                    }
+                }
+            }).to_string()).unwrap();
+            (received, push)
+        });
+
+        let cancel = || client.sign_action(BulkCancel::new(&[CancelRequest::new(0, 42)]).unwrap()).unwrap();
+        let _: ExchangeResponseData<CancelStatuses> = ws.post_action(&cancel()).await.unwrap();
+        assert_eq!(budget.snapshot().remaining(), 0);
+
+        let error = ws.post_action::<_, ExchangeResponseData<CancelStatuses>>(&cancel()).await.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::ActionBudgetExhausted { used: 10_000, cap: 10_000, .. }));
+        let (mut received, _push) = responder.await.unwrap();
+        assert!(received.try_recv().is_err(), "The refused action is not sent");
+    }
+
+    #[tokio::test]
+    async fn test_post_responses_correlated_by_id() {
+        let (url, mut received, push) = start_server().await;
+        let ws = WsClient::connect(&url).await.unwrap();
+
+        // Answer both requests in reverse order
+        let responder = tokio::spawn(async move {
+            let first = received.recv().await.unwrap();
+            let second = received.recv().await.unwrap();
+            for request in [second, first] {
+                let coin = request["request"]["payload"]["coin"].clone();
+                push.send(json!({
+                    "channel": "post",
+                    "data": {
+                        "id": request["id"],
+                        "response": {"type": "info", "payload": {"type": "l2Book", "data": {"coin": coin, "time": 1, "levels": [[], []]}}}
+                    }
+                }).to_string()).unwrap();
+            }
+            push
+        });
+
+        let btc = L2BookRequest::new("BTC");
+        let eth = L2BookRequest::new("ETH");
+        let (btc_book, eth_book) = tokio::join!(
+            ws.post_info::<_, L2BookResponse>(&btc),
+            ws.post_info::<_, L2BookResponse>(&eth),
+        );
+        assert_eq!(btc_book.unwrap().coin, "BTC");
+        assert_eq!(eth_book.unwrap().coin, "ETH");
+        responder.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_post_timeout_and_error() {
+        let (url, mut received, push) = start_server().await;
+        let config = HyperLiquidConfig::default()
+            .with_ws_url(&url)
+            .with_ws_post_timeout(Duration::from_millis(100));
+        let ws = WsClient::connect_with_config(config).await.unwrap();
+
+        let error = ws.post_info::<_, L2BookResponse>(&L2BookRequest::new("BTC")).await.unwrap_err();
+        assert!(matches!(error, HyperLiquidError::Timeout));
+        received.recv().await.unwrap();
+
+        let responder = tokio::spawn(async move {
+            let request = received.recv().await.unwrap();
+            push.send(json!({
+                "channel": "post",
+                "data": {"id": request["id"], "response": {"type": "error", "payload": "Invalid request"}}
+            }).to_string()).unwrap();
+            push
+        });
+
+        let error = ws.post_info::<_, L2BookResponse>(&L2BookRequest::new("BTC")).await.unwrap_err();
+        assert!(error.to_string().contains("Invalid request"));
+        responder.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_split_posts_while_reading() {
+        let (url, mut received, push) = start_server().await;
+        let (sender, mut receiver) = WsClient::connect(&url).await.unwrap().split();
+
+        let reader = tokio::spawn(async move {
+            match tokio::time::timeout(Duration::from_secs(5), receiver.next()).await.unwrap() {
+                Some(WsMessage::Trades(trades)) => trades.len(),
+                other => panic!("Expected trades, got {:?}", other),
+            }
+        });
+
+        let responder = tokio::spawn(async move {
+            let request = received.recv().await.unwrap();
+            push.send(json!({
+                "channel": "trades",
+                "data": [{"coin": "BTC", "side": "B", "px": "50000", "sz": "0.1", "time": 1, "hash": "0x0", "tid": 1, "users": [TEST_USER, TEST_USER]}]
+            }).to_string()).unwrap();
+            push.send(json!({
+                "channel": "post",
+                "data": {
+                    "id": request["id"],
+                    "response": {"type": "info", "payload": {"type": "l2Book", "data": {"coin": "BTC", "time": 1, "levels": [[], []]}}}
+                }
+            }).to_string()).unwrap();
+            push
+        });
+
+        let book: L2BookResponse = sender.post_info(&L2BookRequest::new("BTC")).await.unwrap();
+        assert_eq!(book.coin, "BTC");
+        assert_eq!(reader.await.unwrap(), 1);
+        responder.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_slow_reader_is_told_about_dropped_messages() {
+        let (url, mut received, push) = start_server().await;
+        let config = HyperLiquidConfig::default().with_ws_url(&url).with_ws_message_buffer(2);
+        let mut ws = WsClient::connect_with_config(config).await.unwrap();
+        let pong = json!({"channel": "pong"}).to_string();
+
+        // The post response is handled after the five pongs, so all of them have been
+        // buffered or dropped once it returns
+        let responder = tokio::spawn(async move {
+            let request = received.recv().await.unwrap();
+            for _ in 0..5 {
+                push.send(pong.clone()).unwrap();
+            }
+            push.send(json!({
+                "channel": "post",
+                "data": {
+                    "id": request["id"],
+                    "response": {"type": "info", "payload": {"type": "l2Book", "data": {"coin": "BTC", "time": 1, "levels": [[], []]}}}
+                }
+            }).to_string()).unwrap();
+            push
+        });
+        ws.post_info::<_, L2BookResponse>(&L2BookRequest::new("BTC")).await.unwrap();
+        let push = responder.await.unwrap();
+
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Pong));
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Pong));
+        push.send(json!({"channel": "error", "data": "after"}).to_string()).unwrap();
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Lagged { dropped: 3 }));
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Error(message) if message == "after"));
+    }
+
+    #[tokio::test]
+    async fn test_connects_through_http_proxy() {
+        let (url, mut received, _push) = start_server().await;
+        let (proxy, mut heads) = start_connect_proxy().await;
+        let config = HyperLiquidConfig::default().with_ws_url(&url).with_proxy(proxy);
+
+        let ws = WsClient::connect_with_config(config).await.unwrap();
+        ws.subscribe(Subscription::trades("BTC")).unwrap();
+
+        let head = heads.recv().await.unwrap();
+        let authority = url.trim_start_matches("ws://");
+        assert!(head.starts_with(&format!("CONNECT {} HTTP/1.1\r\n", authority)), "{}", head);
+        assert_eq!(received.recv().await.unwrap()["subscription"]["type"], "trades");
+    }
+
+    #[tokio::test]
+    async fn test_connects_through_socks_proxy_by_name() {
+        let (url, mut received, _push) = start_server().await;
+        let (proxy, mut domains) = start_socks_proxy().await;
+        let port = url.rsplit(':').next().unwrap();
+        let config = HyperLiquidConfig::default()
+            .with_ws_url(format!("ws://api.hyperliquid.test:{}", port))
+            .with_proxy(proxy);
+
+        let ws = WsClient::connect_with_config(config).await.unwrap();
+        ws.subscribe(Subscription::trades("BTC")).unwrap();
+
+        assert_eq!(domains.recv().await.unwrap(), "api.hyperliquid.test");
+        assert_eq!(received.recv().await.unwrap()["subscription"]["type"], "trades");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_override_and_local_address_apply_to_websocket() {
+        let (url, mut received, _push) = start_server().await;
+        let port = url.rsplit(':').next().unwrap();
+        let config = HyperLiquidConfig::default()
+            .with_ws_url(format!("ws://api.hyperliquid.test:{}", port))
+            .with_resolve("api.hyperliquid.test", "127.0.0.1".parse().unwrap())
+            .with_local_address("127.0.0.1".parse().unwrap());
+
+        let ws = WsClient::connect_with_config(config).await.unwrap();
+        ws.subscribe(Subscription::trades("BTC")).unwrap();
+        assert_eq!(received.recv().await.unwrap()["subscription"]["type"], "trades");
+    }
+
+    #[tokio::test]
+    async fn test_invalid_root_certificate_fails_websocket_connect() {
+        let (url, _received, _push) = start_server().await;
+        let config = HyperLiquidConfig::default()
+            .with_ws_url(&url)
+            .with_root_certificate("/nonexistent/ca.pem");
+
+        let result = WsClient::connect_with_config(config).await;
+        assert!(matches!(result, Err(HyperLiquidError::ConfigError(message)) if message.contains("ca.pem")));
+    }
+
+    #[tokio::test]
+    async fn test_full_buffer_keeps_reconnect_events() {
+        let (url, mut connections) = start_reconnecting_server().await;
+        // The heartbeat also flushes held-back events once the reader makes room
+        let config = fast_reconnect_config(&url)
+            .with_ws_message_buffer(1)
+            .with_ws_ping_interval(Duration::from_millis(50));
+        let mut ws = WsClient::connect_with_config(config).await.unwrap();
+        let pong = json!({"channel": "pong"}).to_string();
+
+        let mut first = connections.recv().await.unwrap();
+        ws.subscribe(Subscription::l2_book("BTC")).unwrap();
+        first.received.recv().await.unwrap();
+        for _ in 0..3 {
+            first.push.send(pong.clone()).unwrap();
+        }
+        drop(first.push);
+
+        let mut second = tokio::time::timeout(Duration::from_secs(5), connections.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let responder = tokio::spawn(async move {
+            let request = loop {
+                let frame = second.received.recv().await.unwrap();
+                if frame["method"] == "post" {
+                    break frame;
+                }
+            };
+            second.push.send(pong).unwrap();
+            second.push.send(json!({
+                "channel": "post",
+                "data": {
+                    "id": request["id"],
+                    "response": {"type": "info", "payload": {"type": "l2Book", "data": {"coin": "BTC", "time": 1, "levels": [[], []]}}}
+                }
+            }).to_string()).unwrap();
+            second.push
+        });
+        // Posts queued while reconnecting are never sent, so retry until one lands on the
+        // new connection; its response arrives after every message above
+        let sender = ws.sender();
+        let _push = loop {
+            if sender.post_info::<_, L2BookResponse>(&L2BookRequest::new("BTC")).await.is_ok() {
+                break responder.await.unwrap();
+            }
+        };
+
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Pong));
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Lagged { dropped: 2 }));
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Gap { .. }));
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Reconnected { .. }));
+        assert!(matches!(next_message(&mut ws).await, WsMessage::Lagged { dropped: 1 }));
+    }
+}